@@ -0,0 +1,86 @@
+//! Charset detection and transcoding for raw pages before they are parsed as text.
+
+use encoding_rs::{Encoding, UTF_8, WINDOWS_1252};
+
+/// How far into a page a `<meta charset>` declaration is looked for (the HTML spec prescans 1024).
+const META_PRESCAN_BYTES: usize = 1024;
+
+/// Decode a raw page to text. The charset comes from a byte-order mark, the `charset` parameter
+/// of `content_type`, or an HTML `<meta charset>` / `http-equiv` declaration, in that order.
+/// Without one, valid UTF-8 stays UTF-8 and anything else is read as Windows-1252 (which is
+/// what browsers do for undeclared ISO-8859-1 pages).
+pub fn decode_text(body: &[u8], content_type: &str) -> String {
+    let encoding = detect_encoding(body, content_type);
+    let (text, _, _) = encoding.decode(body);
+    text.into_owned()
+}
+
+/// The encoding [`decode_text`] would use for `body`.
+pub fn detect_encoding(body: &[u8], content_type: &str) -> &'static Encoding {
+    if let Some((encoding, _)) = Encoding::for_bom(body) {
+        return encoding;
+    }
+    if let Some(encoding) = charset_param(content_type).and_then(|label| Encoding::for_label(label.as_bytes())) {
+        return encoding;
+    }
+    if !content_type.contains("json") {
+        if let Some(encoding) = meta_charset(body) {
+            return encoding;
+        }
+    }
+    if std::str::from_utf8(body).is_ok() {
+        UTF_8
+    } else {
+        WINDOWS_1252
+    }
+}
+
+fn charset_param(content_type: &str) -> Option<&str> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        name.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches(['"', '\'']))
+    })
+}
+
+/// A `charset=` declared near the top of an HTML page, covering both `<meta charset="...">`
+/// and `<meta http-equiv="Content-Type" content="text/html; charset=...">`.
+fn meta_charset(body: &[u8]) -> Option<&'static Encoding> {
+    let head = &body[..body.len().min(META_PRESCAN_BYTES)];
+    let head = String::from_utf8_lossy(head).to_ascii_lowercase();
+    head.match_indices("<meta").find_map(|(start, _)| {
+        let tag = &head[start..];
+        let tag = &tag[..tag.find('>').unwrap_or(tag.len())];
+        let at = tag.find("charset=")? + "charset=".len();
+        let label = tag[at..]
+            .trim_start_matches(['"', '\''])
+            .split(|c: char| c == '"' || c == '\'' || c == ';' || c == '/' || c.is_whitespace())
+            .next()?;
+        // A UTF-16 declaration in an ASCII-compatible prescan is wrong; browsers treat it as UTF-8.
+        Encoding::for_label(label.as_bytes()).map(|encoding| encoding.output_encoding())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transcodes_declared_and_undeclared_legacy_pages() {
+        // "Café – 15 €" in Windows-1252.
+        let latin = b"<p>Caf\xe9 \x96 15 \x80</p>";
+        assert_eq!(decode_text(latin, "text/html; charset=ISO-8859-1"), "<p>Café – 15 €</p>");
+        assert_eq!(decode_text(latin, "text/html"), "<p>Café – 15 €</p>");
+
+        let meta = b"<html><head><META http-equiv=\"Content-Type\" content=\"text/html; charset=windows-1252\"></head><p>Caf\xe9</p>";
+        assert_eq!(detect_encoding(meta, "text/html"), WINDOWS_1252);
+        let meta = b"<meta charset='koi8-r'><p>\xf0\xd2\xc9\xd7\xc5\xd4</p>";
+        assert!(decode_text(meta, "text/html").ends_with("<p>Привет</p>"));
+
+        // The header wins over the page, and a BOM wins over both.
+        assert_eq!(detect_encoding(meta, "text/html; charset=\"utf-8\""), UTF_8);
+        assert_eq!(decode_text("\u{feff}Café".as_bytes(), "text/html; charset=latin1"), "Café");
+        assert_eq!(decode_text("Café".as_bytes(), "text/html"), "Café");
+    }
+}
@@ -0,0 +1,4 @@
+//! Adapters scaffolded by `rhof-cli new-adapter`; each registers itself with the adapter registry.
+
+#[path = "sample-source.rs"]
+pub mod sample_source;
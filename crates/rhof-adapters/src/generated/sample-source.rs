@@ -1,7 +1,17 @@
 //! Generated adapter scaffold for sample-source.
 //!
-//! Integrate this into `adapter_for_source` and add fixture parsing logic in `crates/rhof-adapters/src/lib.rs`
-//! or refactor adapters into modules when expanding beyond the initial set.
+//! Declared in `src/generated/mod.rs` and registered with the adapter registry below, so the source
+//! resolves as soon as it is listed in `sources.yaml`. It starts out with the generic HTML
+//! title/link adapter; swap `build` for a source-specific adapter once parsing needs more.
+
+use crate::{html_title_link_adapter, AdapterRegistration};
 
 pub const SOURCE_ID: &str = "sample-source";
 pub const EXTRACTOR_VERSION: &str = "sample-source-v1";
+
+inventory::submit! {
+    AdapterRegistration {
+        source_id: SOURCE_ID,
+        build: || Box::new(html_title_link_adapter(SOURCE_ID, EXTRACTOR_VERSION)),
+    }
+}
@@ -0,0 +1,366 @@
+//! Declarative JSON feeds: a source whose `fixtures/<source_id>/json_mapping.yaml` maps JSONPath
+//! or JSON-pointer expressions to draft fields needs no parsing code of its own.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use async_trait::async_trait;
+use rhof_core::{Field, OpportunityDraft};
+use rhof_storage::HttpFetcher;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use crate::{
+    bundle_to_drafts, detect_pay_unit, health_check_url, json_items, probe_json_listing, set_from_raw, text_or_none,
+    AdapterContext, AdapterError, AdapterHealth, Crawlability, DetailTarget, FetchedPage, FixtureBundle, JsonPager,
+    ListingTarget, SourceAdapter,
+};
+
+/// File under `fixtures/<source_id>/` that turns a source into a [`GenericJsonAdapter`].
+pub const JSON_MAPPING_FILE: &str = "json_mapping.yaml";
+
+/// Where each draft field sits in one result. Every expression is either a JSONPath relative to
+/// the result (`$.salary.min`, `$['job title']`, `$.tags[0]`) or a JSON pointer (`/salary/min`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JsonFieldMap {
+    pub title: String,
+    #[serde(default)]
+    pub external_id: Option<String>,
+    #[serde(default)]
+    pub detail_url: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub pay_model: Option<String>,
+    /// Free text such as `per hour` or `/task`, normalized like HTML pay text.
+    #[serde(default)]
+    pub pay_unit: Option<String>,
+    #[serde(default)]
+    pub pay_rate_min: Option<String>,
+    #[serde(default)]
+    pub pay_rate_max: Option<String>,
+    #[serde(default)]
+    pub currency: Option<String>,
+    #[serde(default)]
+    pub min_hours_per_week: Option<String>,
+    #[serde(default)]
+    pub verification_requirements: Option<String>,
+    #[serde(default)]
+    pub geo_constraints: Option<String>,
+    #[serde(default)]
+    pub one_off_vs_ongoing: Option<String>,
+    /// An array of strings, or one string taken as a single entry.
+    #[serde(default)]
+    pub payment_methods: Option<String>,
+    #[serde(default)]
+    pub apply_url: Option<String>,
+    /// An array of strings, or one string taken as a single entry.
+    #[serde(default)]
+    pub requirements: Option<String>,
+}
+
+impl JsonFieldMap {
+    fn expressions_mut(&mut self) -> impl Iterator<Item = &mut String> {
+        [
+            &mut self.external_id,
+            &mut self.detail_url,
+            &mut self.description,
+            &mut self.pay_model,
+            &mut self.pay_unit,
+            &mut self.pay_rate_min,
+            &mut self.pay_rate_max,
+            &mut self.currency,
+            &mut self.min_hours_per_week,
+            &mut self.verification_requirements,
+            &mut self.geo_constraints,
+            &mut self.one_off_vs_ongoing,
+            &mut self.payment_methods,
+            &mut self.apply_url,
+            &mut self.requirements,
+        ]
+        .into_iter()
+        .flatten()
+        .chain([&mut self.title])
+    }
+}
+
+/// Contents of a source's [`JSON_MAPPING_FILE`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JsonMapping {
+    /// The results array, from the response root: `$.data.jobs[*]`, `/data/jobs`, or empty (or
+    /// `$`) when the response is the array itself.
+    #[serde(default)]
+    pub items: String,
+    /// The next page's URL, from the response root, for cursor pagination. Listing URLs containing
+    /// `{page}` are numbered instead, until a page has no results.
+    #[serde(default)]
+    pub next: Option<String>,
+    pub fields: JsonFieldMap,
+    /// Bump when changing the mapping changes parsed output; defaults to `<source_id>-json-v1`.
+    #[serde(default)]
+    pub extractor_version: Option<String>,
+}
+
+impl JsonMapping {
+    /// The same mapping with every expression turned into a JSON pointer.
+    pub fn to_pointers(&self) -> Result<Self, String> {
+        let mut mapping = self.clone();
+        let items = mapping.items.trim();
+        let items = items.strip_suffix("[*]").or_else(|| items.strip_suffix(".*")).unwrap_or(items);
+        mapping.items = json_path_to_pointer(items)?;
+        if let Some(next) = &mut mapping.next {
+            *next = json_path_to_pointer(next)?;
+        }
+        for expression in mapping.fields.expressions_mut() {
+            *expression = json_path_to_pointer(expression)?;
+        }
+        Ok(mapping)
+    }
+}
+
+/// Convert a JSONPath made of child names (`.name`, `['name']`, `["name"]`) and array indexes
+/// (`[0]`) to the equivalent RFC 6901 JSON pointer. JSON pointers and the empty string pass
+/// through unchanged; wildcards, slices and filters are rejected.
+pub fn json_path_to_pointer(expression: &str) -> Result<String, String> {
+    let expression = expression.trim();
+    if expression.is_empty() || expression.starts_with('/') {
+        return Ok(expression.to_string());
+    }
+    let unsupported = |what: &str| format!("JSONPath `{expression}`: {what}");
+    let mut rest = expression
+        .strip_prefix('$')
+        .ok_or_else(|| unsupported("expected a JSONPath starting with `$` or a JSON pointer starting with `/`"))?;
+    let mut pointer = String::new();
+    while !rest.is_empty() {
+        let segment = if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            rest = &after[end..];
+            &after[..end]
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let end = after.find(']').ok_or_else(|| unsupported("unclosed `[`"))?;
+            rest = &after[end + 1..];
+            let inner = after[..end].trim();
+            let quoted = ['\'', '"']
+                .into_iter()
+                .find_map(|quote| inner.strip_prefix(quote)?.strip_suffix(quote));
+            match quoted {
+                Some(name) => name,
+                None if !inner.is_empty() && inner.bytes().all(|b| b.is_ascii_digit()) => inner,
+                None => return Err(unsupported(&format!("`[{inner}]` is not a quoted name or an array index"))),
+            }
+        } else {
+            return Err(unsupported("expected `.` or `[` after each step"));
+        };
+        if segment.is_empty() || segment == "*" {
+            return Err(unsupported("wildcards and empty names are not supported"));
+        }
+        pointer.push('/');
+        pointer.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+    }
+    Ok(pointer)
+}
+
+/// Adapter for any JSON listing API described by a [`JsonMapping`]. Fields' evidence points at the
+/// exact JSON pointer of the value in the stored response (`/data/jobs/3/salary/min`). Unlike
+/// [`ApiAdapter`](crate::ApiAdapter) it sends no credential, and its fetches respect robots.txt.
+#[derive(Debug, Clone)]
+pub struct GenericJsonAdapter {
+    source_id: String,
+    crawlability: Crawlability,
+    extractor_version: String,
+    /// The source's mapping with every expression converted to a JSON pointer.
+    mapping: JsonMapping,
+}
+
+impl GenericJsonAdapter {
+    pub fn new(source_id: impl Into<String>, crawlability: Crawlability, mapping: &JsonMapping) -> anyhow::Result<Self> {
+        let source_id = source_id.into();
+        let extractor_version = mapping
+            .extractor_version
+            .clone()
+            .unwrap_or_else(|| format!("{source_id}-json-v1"));
+        let mapping = mapping.to_pointers().map_err(anyhow::Error::msg)?;
+        Ok(Self { source_id, crawlability, extractor_version, mapping })
+    }
+
+    pub fn mapping_path(workspace_root: &Path, source_id: &str) -> PathBuf {
+        workspace_root.join("fixtures").join(source_id).join(JSON_MAPPING_FILE)
+    }
+
+    /// The adapter described by the source's `json_mapping.yaml`, or `None` when it has none.
+    pub fn load(workspace_root: &Path, source_id: &str, crawlability: Crawlability) -> anyhow::Result<Option<Self>> {
+        let path = Self::mapping_path(workspace_root, source_id);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let text = fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        let mapping = serde_yaml::from_str(&text).with_context(|| format!("parsing {}", path.display()))?;
+        Self::new(source_id, crawlability, &mapping)
+            .map(Some)
+            .with_context(|| format!("validating {}", path.display()))
+    }
+
+    fn pager<'a>(&'a self, ctx: &AdapterContext) -> JsonPager<'a> {
+        JsonPager {
+            source_id: &self.source_id,
+            robots: ctx.robots(),
+            headers: &[],
+            items_pointer: &self.mapping.items,
+            next_pointer: self.mapping.next.as_deref(),
+        }
+    }
+
+    fn item_draft(&self, bundle: &FixtureBundle, index: usize, item: &JsonValue) -> OpportunityDraft {
+        let fields = &self.mapping.fields;
+        let at = |pointer: &Option<String>| {
+            let pointer = pointer.as_deref()?;
+            let value = item.pointer(pointer).filter(|v| !v.is_null())?;
+            let path = format!("{}/{index}{pointer}", self.mapping.items);
+            Some((value, path, value.to_string()))
+        };
+        let text = |value: &JsonValue| match value {
+            JsonValue::String(s) => text_or_none(s.clone()),
+            other => Some(other.to_string()),
+        };
+        let number = |value: &JsonValue| value.as_f64().or_else(|| value.as_str()?.trim().parse().ok());
+        let list = |value: &JsonValue| {
+            let entries = match value {
+                JsonValue::Array(values) => values.iter().filter_map(text).collect::<Vec<_>>(),
+                other => text(other).into_iter().collect(),
+            };
+            (!entries.is_empty()).then_some(entries)
+        };
+
+        let mut draft = OpportunityDraft {
+            source_id: self.source_id.clone(),
+            listing_url: Some(bundle.captured_from_url.clone()),
+            detail_url: at(&fields.detail_url).and_then(|(value, _, _)| text(value)),
+            external_id: at(&fields.external_id).and_then(|(value, _, _)| text(value)),
+            language: None,
+            fetched_at: bundle.fetched_at,
+            extractor_version: bundle.extractor_version.clone(),
+            title: Field::empty(),
+            description: Field::empty(),
+            pay_model: Field::empty(),
+            pay_unit: Field::empty(),
+            pay_rate_min: Field::empty(),
+            pay_rate_max: Field::empty(),
+            currency: Field::empty(),
+            min_hours_per_week: Field::empty(),
+            verification_requirements: Field::empty(),
+            geo_constraints: Field::empty(),
+            one_off_vs_ongoing: Field::empty(),
+            payment_methods: Field::empty(),
+            apply_url: Field::empty(),
+            requirements: Field::empty(),
+        };
+        let text_fields = [
+            (&mut draft.title, Some(fields.title.clone())),
+            (&mut draft.description, fields.description.clone()),
+            (&mut draft.pay_model, fields.pay_model.clone()),
+            (&mut draft.currency, fields.currency.clone()),
+            (&mut draft.verification_requirements, fields.verification_requirements.clone()),
+            (&mut draft.geo_constraints, fields.geo_constraints.clone()),
+            (&mut draft.one_off_vs_ongoing, fields.one_off_vs_ongoing.clone()),
+            (&mut draft.apply_url, fields.apply_url.clone()),
+        ];
+        for (field, pointer) in text_fields {
+            if let Some((value, path, snippet)) = at(&pointer) {
+                set_from_raw(field, text(value), bundle, &path, &snippet);
+            }
+        }
+        if let Some((value, path, snippet)) = at(&fields.pay_unit) {
+            let unit = text(value).and_then(|unit| detect_pay_unit(&unit));
+            set_from_raw(&mut draft.pay_unit, unit.map(str::to_string), bundle, &path, &snippet);
+        }
+        let number_fields = [
+            (&mut draft.pay_rate_min, &fields.pay_rate_min),
+            (&mut draft.pay_rate_max, &fields.pay_rate_max),
+            (&mut draft.min_hours_per_week, &fields.min_hours_per_week),
+        ];
+        for (field, pointer) in number_fields {
+            if let Some((value, path, snippet)) = at(pointer) {
+                set_from_raw(field, number(value), bundle, &path, &snippet);
+            }
+        }
+        for (field, pointer) in [(&mut draft.payment_methods, &fields.payment_methods), (&mut draft.requirements, &fields.requirements)] {
+            if let Some((value, path, snippet)) = at(pointer) {
+                set_from_raw(field, list(value), bundle, &path, &snippet);
+            }
+        }
+        draft
+    }
+}
+
+#[async_trait]
+impl SourceAdapter for GenericJsonAdapter {
+    fn source_id(&self) -> &str {
+        &self.source_id
+    }
+
+    fn crawlability(&self) -> Crawlability {
+        self.crawlability
+    }
+
+    fn extractor_version(&self) -> &str {
+        &self.extractor_version
+    }
+
+    /// Check that the first listing page still has a results array where `items` points.
+    async fn health_check(&self, http: &HttpFetcher, ctx: &AdapterContext, targets: &[ListingTarget]) -> AdapterHealth {
+        let Some(url) = health_check_url(targets) else {
+            return AdapterHealth::skipped(&self.source_id, "source has no listing URL");
+        };
+        probe_json_listing(http, ctx, &self.source_id, &url, &[], &self.mapping.items).await
+    }
+
+    async fn fetch_listing(
+        &self,
+        http: &HttpFetcher,
+        ctx: &AdapterContext,
+        targets: &[ListingTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        self.pager(ctx).fetch_listing(http, ctx, targets).await
+    }
+
+    /// One draft per titled result; a bundle without a raw response falls back to its parsed
+    /// records. A response without a results array at `items` means the API changed shape.
+    fn parse_listing(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        if bundle.source_id != self.source_id {
+            return Err(AdapterError::source_mismatch(bundle, &self.source_id));
+        }
+        let Some(text) = bundle.raw_artifact.inline_text.as_deref() else {
+            return Ok(bundle_to_drafts(bundle));
+        };
+        let body: JsonValue = serde_json::from_str(text)
+            .map_err(|err| AdapterError::ParseFailed(format!("parsing JSON response: {err}")))?;
+        if !body.pointer(&self.mapping.items).is_some_and(JsonValue::is_array) {
+            return Err(AdapterError::SchemaMismatch(format!(
+                "JSON response has no results array at `{}`",
+                self.mapping.items
+            )));
+        }
+        Ok(json_items(&body, &self.mapping.items)
+            .iter()
+            .enumerate()
+            .map(|(index, item)| self.item_draft(bundle, index, item))
+            .filter(|draft| draft.title.value.is_some())
+            .collect())
+    }
+
+    async fn fetch_detail(
+        &self,
+        _http: &HttpFetcher,
+        _ctx: &AdapterContext,
+        _targets: &[DetailTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        Ok(Vec::new())
+    }
+
+    fn parse_detail(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        self.parse_listing(bundle)
+    }
+}
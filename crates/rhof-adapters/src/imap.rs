@@ -0,0 +1,451 @@
+//! Newsletter ingestion over IMAP (`imap` feature): each message's raw MIME is one listing page,
+//! and every job link in its HTML body becomes a draft that needs manual review.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{source_env_name, SourceAdapter};
+
+/// Mailbox settings for `mode: email`. The password never lives in `sources.yaml`: it is read
+/// from the environment variable named by `password_env` when the mailbox is fetched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ImapSourceConfig {
+    pub host: String,
+    #[serde(default = "default_port")]
+    pub port: u16,
+    /// Connect with implicit TLS (IMAPS); plain connections are only for local test servers.
+    #[serde(default = "default_tls")]
+    pub tls: bool,
+    pub username: String,
+    /// Defaults to `RHOF_<SOURCE_ID>_IMAP_PASSWORD`.
+    #[serde(default)]
+    pub password_env: Option<String>,
+    #[serde(default = "default_mailbox")]
+    pub mailbox: String,
+    /// IMAP `SEARCH` criteria selecting the messages to ingest, e.g. `SINCE 1-Oct-2026`.
+    #[serde(default = "default_search")]
+    pub search: String,
+    /// Only the newest this many matching messages are fetched per run.
+    #[serde(default = "default_max_messages")]
+    pub max_messages: usize,
+}
+
+fn default_port() -> u16 {
+    993
+}
+
+fn default_tls() -> bool {
+    true
+}
+
+fn default_mailbox() -> String {
+    "INBOX".to_string()
+}
+
+fn default_search() -> String {
+    "ALL".to_string()
+}
+
+fn default_max_messages() -> usize {
+    20
+}
+
+impl ImapSourceConfig {
+    pub fn password_env_for(&self, source_id: &str) -> String {
+        self.password_env
+            .clone()
+            .unwrap_or_else(|| source_env_name(source_id, "IMAP_PASSWORD"))
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.host.trim().is_empty() {
+            return Err("imap.host must not be empty".to_string());
+        }
+        if self.username.trim().is_empty() {
+            return Err("imap.username must not be empty".to_string());
+        }
+        if self.max_messages == 0 {
+            return Err("imap.max_messages must be at least 1".to_string());
+        }
+        if self.search.contains(['\r', '\n']) || self.mailbox.contains(['\r', '\n']) {
+            return Err("imap.search and imap.mailbox must be single-line".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// The adapter for a `mode: email` source. Fails when rhof-adapters was built without the `imap`
+/// feature.
+pub fn imap_adapter(source_id: &str, config: &ImapSourceConfig) -> anyhow::Result<Box<dyn SourceAdapter>> {
+    config.validate().map_err(anyhow::Error::msg)?;
+    #[cfg(feature = "imap")]
+    {
+        Ok(Box::new(client::ImapAdapter::new(source_id, config.clone())))
+    }
+    #[cfg(not(feature = "imap"))]
+    anyhow::bail!("source {source_id} uses `mode: email`, which requires rhof to be built with the `imap` feature")
+}
+
+#[cfg(feature = "imap")]
+pub use client::ImapAdapter;
+
+#[cfg(feature = "imap")]
+mod client {
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use rhof_core::{Field, OpportunityDraft};
+    use rhof_storage::HttpFetcher;
+    use scraper::{Html, Selector};
+    use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+    use tokio::net::TcpStream;
+
+    use super::ImapSourceConfig;
+    use crate::{
+        set_from_raw, text_or_none, AdapterContext, AdapterError, Crawlability, DetailTarget, FetchedPage,
+        FixtureBundle, ListingTarget, SourceAdapter,
+    };
+
+    /// Content type of the raw artifacts an [`ImapAdapter`] stores.
+    const MIME_CONTENT_TYPE: &str = "message/rfc822";
+
+    /// Links in newsletters that never point at a gig.
+    const SKIPPED_LINK_WORDS: [&str; 6] = ["unsubscribe", "preferences", "view in browser", "privacy", "manage", "mailto:"];
+
+    /// Pulls messages from an IMAP mailbox (read-only: `EXAMINE` + `BODY.PEEK`, so nothing is
+    /// marked read) and turns each job link of a message's HTML body into a draft titled with the
+    /// link text. Its drafts always go to manual review.
+    #[derive(Debug, Clone)]
+    pub struct ImapAdapter {
+        source_id: String,
+        extractor_version: String,
+        config: ImapSourceConfig,
+    }
+
+    impl ImapAdapter {
+        pub fn new(source_id: impl Into<String>, config: ImapSourceConfig) -> Self {
+            let source_id = source_id.into();
+            let extractor_version = format!("{source_id}-imap-v1");
+            Self { source_id, extractor_version, config }
+        }
+
+        async fn fetch_messages(&self) -> anyhow::Result<Vec<FetchedPage>> {
+            let env = self.config.password_env_for(&self.source_id);
+            let password = std::env::var(&env)
+                .ok()
+                .filter(|value| !value.is_empty())
+                .ok_or_else(|| anyhow::anyhow!("{env} is not set; export the mailbox password for {}", self.source_id))?;
+            let stream = TcpStream::connect((self.config.host.as_str(), self.config.port)).await?;
+            if !self.config.tls {
+                return self.fetch_over(stream, &password).await;
+            }
+            let roots = tokio_rustls::rustls::RootCertStore::from_iter(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+            let tls = tokio_rustls::rustls::ClientConfig::builder()
+                .with_root_certificates(roots)
+                .with_no_client_auth();
+            let server_name = tokio_rustls::rustls::pki_types::ServerName::try_from(self.config.host.clone())?;
+            let stream = tokio_rustls::TlsConnector::from(Arc::new(tls))
+                .connect(server_name, stream)
+                .await?;
+            self.fetch_over(stream, &password).await
+        }
+
+        async fn fetch_over<S: AsyncRead + AsyncWrite + Unpin>(
+            &self,
+            stream: S,
+            password: &str,
+        ) -> anyhow::Result<Vec<FetchedPage>> {
+            let mut session = ImapSession { stream: BufReader::new(stream), next_tag: 0 };
+            session.read_greeting().await?;
+            session
+                .command(&format!("LOGIN {} {}", quoted(&self.config.username), quoted(password)))
+                .await?;
+            session.command(&format!("EXAMINE {}", quoted(&self.config.mailbox))).await?;
+            let (lines, _) = session.command(&format!("UID SEARCH {}", self.config.search)).await?;
+            let mut uids = lines
+                .iter()
+                .filter_map(|line| line.strip_prefix("* SEARCH"))
+                .flat_map(|ids| ids.split_whitespace().filter_map(|id| id.parse::<u32>().ok()))
+                .collect::<Vec<_>>();
+            uids.sort_unstable();
+            let newest = &uids[uids.len().saturating_sub(self.config.max_messages)..];
+
+            let mut pages = Vec::with_capacity(newest.len());
+            for uid in newest {
+                let (_, literals) = session.command(&format!("UID FETCH {uid} BODY.PEEK[]")).await?;
+                let Some(body) = literals.into_iter().next() else {
+                    continue;
+                };
+                pages.push(FetchedPage {
+                    url: format!(
+                        "imap://{}@{}/{};UID={uid}",
+                        self.config.username.replace('@', "%40"),
+                        self.config.host,
+                        self.config.mailbox
+                    ),
+                    content_type: MIME_CONTENT_TYPE.to_string(),
+                    body,
+                    fetched_at: Utc::now(),
+                    not_modified: false,
+                });
+            }
+            // The messages are already in hand; a failed logout only leaves the session to time out.
+            let _ = session.command("LOGOUT").await;
+            Ok(pages)
+        }
+
+        fn link_draft(&self, bundle: &FixtureBundle, subject: Option<&str>, href: &str, text: &str) -> OpportunityDraft {
+            let mut draft = OpportunityDraft {
+                source_id: self.source_id.clone(),
+                listing_url: Some(bundle.captured_from_url.clone()),
+                detail_url: Some(href.to_string()),
+                external_id: None,
+                language: None,
+                fetched_at: bundle.fetched_at,
+                extractor_version: bundle.extractor_version.clone(),
+                title: Field::empty(),
+                description: Field::empty(),
+                pay_model: Field::empty(),
+                pay_unit: Field::empty(),
+                pay_rate_min: Field::empty(),
+                pay_rate_max: Field::empty(),
+                currency: Field::empty(),
+                min_hours_per_week: Field::empty(),
+                verification_requirements: Field::empty(),
+                geo_constraints: Field::empty(),
+                one_off_vs_ongoing: Field::empty(),
+                payment_methods: Field::empty(),
+                apply_url: Field::empty(),
+                requirements: Field::empty(),
+            };
+            let selector = format!("a[href=\"{href}\"]");
+            set_from_raw(&mut draft.title, text_or_none(text.to_string()), bundle, &selector, text);
+            set_from_raw(&mut draft.apply_url, Some(href.to_string()), bundle, &selector, href);
+            if let Some(subject) = subject {
+                set_from_raw(&mut draft.description, text_or_none(subject.to_string()), bundle, "Subject", subject);
+            }
+            draft
+        }
+    }
+
+    /// `"..."` with IMAP quoted-string escaping.
+    fn quoted(value: &str) -> String {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+
+    struct ImapSession<S> {
+        stream: BufReader<S>,
+        next_tag: u32,
+    }
+
+    impl<S: AsyncRead + AsyncWrite + Unpin> ImapSession<S> {
+        async fn read_greeting(&mut self) -> anyhow::Result<()> {
+            let (line, _) = self.read_line().await?;
+            anyhow::ensure!(line.starts_with("* OK"), "unexpected IMAP greeting: {line}");
+            Ok(())
+        }
+
+        /// Send one tagged command and collect its untagged lines and `{n}` literals until the
+        /// tagged completion, which must be `OK`.
+        async fn command(&mut self, command: &str) -> anyhow::Result<(Vec<String>, Vec<Vec<u8>>)> {
+            self.next_tag += 1;
+            let tag = format!("a{}", self.next_tag);
+            self.stream
+                .get_mut()
+                .write_all(format!("{tag} {command}\r\n").as_bytes())
+                .await?;
+            self.stream.get_mut().flush().await?;
+            let verb = command.split_whitespace().take(2).collect::<Vec<_>>().join(" ");
+            let mut lines = Vec::new();
+            let mut literals = Vec::new();
+            loop {
+                let (line, literal) = self.read_line().await?;
+                literals.extend(literal);
+                if let Some(status) = line.strip_prefix(&format!("{tag} ")) {
+                    anyhow::ensure!(status.starts_with("OK"), "IMAP {verb} failed: {status}");
+                    return Ok((lines, literals));
+                }
+                lines.push(line);
+            }
+        }
+
+        /// One response line (without CRLF), with a trailing `{n}` literal read in full.
+        async fn read_line(&mut self) -> anyhow::Result<(String, Option<Vec<u8>>)> {
+            let mut raw = Vec::new();
+            if self.stream.read_until(b'\n', &mut raw).await? == 0 {
+                anyhow::bail!("IMAP server closed the connection");
+            }
+            let line = String::from_utf8_lossy(&raw).trim_end().to_string();
+            let Some(size) = line
+                .strip_suffix('}')
+                .and_then(|head| head.rsplit_once('{'))
+                .and_then(|(_, size)| size.parse::<usize>().ok())
+            else {
+                return Ok((line, None));
+            };
+            let mut literal = vec![0; size];
+            self.stream.read_exact(&mut literal).await?;
+            // The rest of the response after the literal (e.g. `)`).
+            let mut rest = Vec::new();
+            self.stream.read_until(b'\n', &mut rest).await?;
+            Ok((line, Some(literal)))
+        }
+    }
+
+    #[async_trait]
+    impl SourceAdapter for ImapAdapter {
+        fn source_id(&self) -> &str {
+            &self.source_id
+        }
+
+        fn crawlability(&self) -> Crawlability {
+            Crawlability::Gated
+        }
+
+        fn extractor_version(&self) -> &str {
+            &self.extractor_version
+        }
+
+        fn requires_review(&self) -> bool {
+            true
+        }
+
+        /// Every matching message, newest `max_messages` only; listing targets are ignored.
+        async fn fetch_listing(
+            &self,
+            _http: &HttpFetcher,
+            _ctx: &AdapterContext,
+            _targets: &[ListingTarget],
+        ) -> Result<Vec<FetchedPage>, AdapterError> {
+            self.fetch_messages()
+                .await
+                .map_err(|err| err.context(format!("fetching mailbox {} for {}", self.config.mailbox, self.source_id)).into())
+        }
+
+        /// One draft per distinct http(s) link in the message's HTML body (falling back to bare
+        /// URLs in the text body), titled by the link text, with the subject as description.
+        fn parse_listing(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+            let Some(raw) = bundle.raw_artifact.inline_text.as_deref() else {
+                return Ok(Vec::new());
+            };
+            let message = mail_parser::MessageParser::default()
+                .parse(raw.as_bytes())
+                .ok_or_else(|| AdapterError::ParseFailed(format!("{} is not a MIME message", bundle.captured_from_url)))?;
+            let subject = message.subject();
+            let mut links = Vec::new();
+            if let Some(html) = message.body_html(0) {
+                let document = Html::parse_document(&html);
+                let anchors = Selector::parse("a[href]").expect("static selector");
+                for anchor in document.select(&anchors) {
+                    let href = anchor.value().attr("href").unwrap_or_default().trim().to_string();
+                    let text = anchor.text().collect::<Vec<_>>().join(" ");
+                    links.push((href, text.split_whitespace().collect::<Vec<_>>().join(" ")));
+                }
+            } else if let Some(text) = message.body_text(0) {
+                for word in text.split_whitespace().filter(|word| word.starts_with("http")) {
+                    links.push((word.trim_end_matches(['.', ',', ')', '>']).to_string(), subject.unwrap_or(word).to_string()));
+                }
+            }
+
+            let mut seen = HashSet::new();
+            Ok(links
+                .into_iter()
+                .filter(|(href, text)| {
+                    let lower = format!("{href} {text}").to_ascii_lowercase();
+                    (href.starts_with("https://") || href.starts_with("http://"))
+                        && !text.is_empty()
+                        && !SKIPPED_LINK_WORDS.iter().any(|word| lower.contains(word))
+                })
+                .filter(|(href, _)| seen.insert(href.clone()))
+                .map(|(href, text)| self.link_draft(bundle, subject, &href, &text))
+                .collect())
+        }
+
+        async fn fetch_detail(
+            &self,
+            _http: &HttpFetcher,
+            _ctx: &AdapterContext,
+            _targets: &[DetailTarget],
+        ) -> Result<Vec<FetchedPage>, AdapterError> {
+            Ok(Vec::new())
+        }
+
+        fn parse_detail(&self, _bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::listing_page_bundle;
+
+        const NEWSLETTER: &str = "From: jobs@example.com\r\n\
+Subject: New rater gigs this week\r\n\
+MIME-Version: 1.0\r\n\
+Content-Type: text/html; charset=utf-8\r\n\
+\r\n\
+<p>Hi!</p><a href=\"https://example.com/gigs/1\">Search Quality Rater</a>\
+<a href=\"https://example.com/gigs/2\">Audio  Transcriber</a>\
+<a href=\"https://example.com/gigs/1\">Search Quality Rater</a>\
+<a href=\"https://example.com/unsubscribe\">Unsubscribe</a>\r\n";
+
+        #[tokio::test]
+        async fn fetches_messages_read_only_and_drafts_each_job_link() {
+            use tokio::io::AsyncWriteExt;
+
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let port = listener.local_addr().unwrap().port();
+            tokio::spawn(async move {
+                let (socket, _) = listener.accept().await.unwrap();
+                let mut socket = BufReader::new(socket);
+                socket.get_mut().write_all(b"* OK test server ready\r\n").await.unwrap();
+                let mut line = String::new();
+                while socket.read_line(&mut line).await.unwrap() > 0 {
+                    let (tag, command) = line.trim_end().split_once(' ').unwrap();
+                    let reply = match command {
+                        c if c.starts_with("LOGIN ") && c != "LOGIN \"jobs@example.com\" \"s3cret\"" => {
+                            format!("{tag} NO bad credentials\r\n")
+                        }
+                        "UID SEARCH ALL" => format!("* SEARCH 7 3 9\r\n{tag} OK done\r\n"),
+                        c if c.starts_with("UID FETCH ") => {
+                            assert!(c.ends_with("BODY.PEEK[]"), "messages must not be marked read: {c}");
+                            format!(
+                                "* 1 FETCH (UID 9 BODY[] {{{}}}\r\n{NEWSLETTER})\r\n{tag} OK done\r\n",
+                                NEWSLETTER.len()
+                            )
+                        }
+                        _ => format!("{tag} OK done\r\n"),
+                    };
+                    socket.get_mut().write_all(reply.as_bytes()).await.unwrap();
+                    line.clear();
+                }
+            });
+
+            let config: ImapSourceConfig = serde_yaml::from_str(&format!(
+                "host: 127.0.0.1\nport: {port}\ntls: false\nusername: jobs@example.com\npassword_env: RHOF_IMAP_TEST_PASSWORD\nmax_messages: 2\n"
+            ))
+            .unwrap();
+            let adapter = ImapAdapter::new("newsletter", config);
+            std::env::set_var("RHOF_IMAP_TEST_PASSWORD", "s3cret");
+            let pages = adapter.fetch_messages().await.unwrap();
+            assert_eq!(pages.len(), 2, "only the newest max_messages uids are fetched");
+            assert_eq!(pages[1].url, "imap://jobs%40example.com@127.0.0.1/INBOX;UID=9");
+            assert_eq!(pages[0].body, NEWSLETTER.as_bytes());
+
+            let bundle = listing_page_bundle("newsletter", Crawlability::Gated, adapter.extractor_version(), &pages[0]);
+            let drafts = adapter.parse_listing(&bundle).unwrap();
+            let titles = drafts.iter().map(|d| d.title.value.as_deref().unwrap()).collect::<Vec<_>>();
+            assert_eq!(titles, ["Search Quality Rater", "Audio Transcriber"]);
+            assert_eq!(drafts[0].apply_url.value.as_deref(), Some("https://example.com/gigs/1"));
+            assert_eq!(drafts[0].description.value.as_deref(), Some("New rater gigs this week"));
+            assert_eq!(
+                drafts[0].title.evidence.as_ref().unwrap().selector_or_pointer,
+                "a[href=\"https://example.com/gigs/1\"]"
+            );
+            assert!(adapter.requires_review());
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Public job-board APIs (Greenhouse, Lever): every board is a source configured by its board
+//! token alone, so companies posting gigs there need no parsing code of their own.
+
+use async_trait::async_trait;
+use rhof_core::{Field, OpportunityDraft};
+use rhof_storage::{HttpFetcher, RobotsPolicy};
+use scraper::Html;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use crate::{
+    bundle_to_drafts, detect_pay_unit, probe_json_listing, set_from_raw, text_or_none, AdapterContext, AdapterError,
+    AdapterHealth, Crawlability, DetailTarget, FetchedPage, FixtureBundle, ListingTarget, SourceAdapter,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobBoardProvider {
+    /// `boards-api.greenhouse.io/v1/boards/<token>/jobs`
+    Greenhouse,
+    /// `api.lever.co/v0/postings/<token>`
+    Lever,
+}
+
+impl JobBoardProvider {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Greenhouse => "greenhouse",
+            Self::Lever => "lever",
+        }
+    }
+
+    fn default_api_base(self) -> &'static str {
+        match self {
+            Self::Greenhouse => "https://boards-api.greenhouse.io",
+            Self::Lever => "https://api.lever.co",
+        }
+    }
+
+    /// JSON pointer to the postings array of a board response.
+    fn items_pointer(self) -> &'static str {
+        match self {
+            Self::Greenhouse => "/jobs",
+            Self::Lever => "",
+        }
+    }
+}
+
+/// The `job_board:` block of a `mode: job_board` source in `sources.yaml`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JobBoardConfig {
+    pub provider: JobBoardProvider,
+    /// The company's board token (Greenhouse) or site name (Lever), as in its job-board URL.
+    pub board_token: String,
+    /// Overrides the provider's API host, e.g. `https://api.eu.lever.co` for EU Lever accounts.
+    #[serde(default)]
+    pub api_base: Option<String>,
+}
+
+impl JobBoardConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        let token = self.board_token.trim();
+        if token.is_empty() {
+            return Err("job_board.board_token must not be empty".to_string());
+        }
+        if !token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(format!("job_board.board_token `{token}` may only contain letters, digits, `-` and `_`"));
+        }
+        if let Some(base) = &self.api_base {
+            match url::Url::parse(base) {
+                Ok(url) if matches!(url.scheme(), "http" | "https") => {}
+                _ => return Err(format!("job_board.api_base `{base}` must be an absolute http(s) URL")),
+            }
+        }
+        Ok(())
+    }
+
+    /// The one URL that returns every open posting of the board.
+    pub fn listing_url(&self) -> String {
+        let base = self
+            .api_base
+            .as_deref()
+            .unwrap_or(self.provider.default_api_base())
+            .trim_end_matches('/');
+        let token = self.board_token.trim();
+        match self.provider {
+            JobBoardProvider::Greenhouse => format!("{base}/v1/boards/{token}/jobs?content=true"),
+            JobBoardProvider::Lever => format!("{base}/v0/postings/{token}?mode=json"),
+        }
+    }
+}
+
+/// Adapter for one Greenhouse or Lever board. The board URL comes from [`JobBoardConfig`], so the
+/// source's `listing_urls` are not used. Like [`ApiAdapter`](crate::ApiAdapter) it skips
+/// robots.txt: these are documented public APIs, not crawled pages.
+#[derive(Debug, Clone)]
+pub struct JobBoardAdapter {
+    source_id: String,
+    extractor_version: String,
+    config: JobBoardConfig,
+}
+
+impl JobBoardAdapter {
+    pub fn new(source_id: impl Into<String>, config: JobBoardConfig) -> anyhow::Result<Self> {
+        config.validate().map_err(anyhow::Error::msg)?;
+        let source_id = source_id.into();
+        let extractor_version = format!("{source_id}-{}-v1", config.provider.name());
+        Ok(Self { source_id, extractor_version, config })
+    }
+
+    fn empty_draft(&self, bundle: &FixtureBundle) -> OpportunityDraft {
+        OpportunityDraft {
+            source_id: self.source_id.clone(),
+            listing_url: Some(bundle.captured_from_url.clone()),
+            detail_url: None,
+            external_id: None,
+            language: None,
+            fetched_at: bundle.fetched_at,
+            extractor_version: bundle.extractor_version.clone(),
+            title: Field::empty(),
+            description: Field::empty(),
+            pay_model: Field::empty(),
+            pay_unit: Field::empty(),
+            pay_rate_min: Field::empty(),
+            pay_rate_max: Field::empty(),
+            currency: Field::empty(),
+            min_hours_per_week: Field::empty(),
+            verification_requirements: Field::empty(),
+            geo_constraints: Field::empty(),
+            one_off_vs_ongoing: Field::empty(),
+            payment_methods: Field::empty(),
+            apply_url: Field::empty(),
+            requirements: Field::empty(),
+        }
+    }
+
+    fn posting_draft(&self, bundle: &FixtureBundle, index: usize, posting: &JsonValue) -> OpportunityDraft {
+        let prefix = format!("{}/{index}", self.config.provider.items_pointer());
+        let at = |pointer: &str| {
+            let value = posting.pointer(pointer).filter(|v| !v.is_null())?;
+            let text = match value {
+                JsonValue::String(s) => text_or_none(s.clone())?,
+                other => other.to_string(),
+            };
+            Some((text, format!("{prefix}{pointer}")))
+        };
+        let mut draft = self.empty_draft(bundle);
+        let set = |field: &mut Field<String>, found: Option<(String, String)>, clean: fn(&str) -> String| {
+            if let Some((raw, path)) = found {
+                set_from_raw(field, text_or_none(clean(&raw)), bundle, &path, &raw);
+            }
+        };
+        match self.config.provider {
+            JobBoardProvider::Greenhouse => {
+                draft.external_id = at("/id").map(|(id, _)| id);
+                draft.detail_url = at("/absolute_url").map(|(url, _)| url);
+                set(&mut draft.title, at("/title"), str::to_string);
+                set(&mut draft.description, at("/content"), html_text);
+                set(&mut draft.geo_constraints, at("/location/name"), str::to_string);
+                set(&mut draft.apply_url, at("/absolute_url"), str::to_string);
+            }
+            JobBoardProvider::Lever => {
+                draft.external_id = at("/id").map(|(id, _)| id);
+                draft.detail_url = at("/hostedUrl").map(|(url, _)| url);
+                set(&mut draft.title, at("/text"), str::to_string);
+                set(&mut draft.description, at("/descriptionPlain"), str::to_string);
+                set(&mut draft.geo_constraints, at("/categories/location"), str::to_string);
+                set(&mut draft.one_off_vs_ongoing, at("/categories/commitment"), str::to_string);
+                set(&mut draft.apply_url, at("/applyUrl").or_else(|| at("/hostedUrl")), str::to_string);
+                set(&mut draft.currency, at("/salaryRange/currency"), |code| code.to_ascii_uppercase());
+                if let Some((interval, path)) = at("/salaryRange/interval") {
+                    // Lever intervals read like `per-hour-wage` or `per-year-salary`.
+                    let unit = detect_pay_unit(&interval.replace('-', " ")).map(str::to_string);
+                    set_from_raw(&mut draft.pay_unit, unit, bundle, &path, &interval);
+                }
+                for (field, pointer) in [(&mut draft.pay_rate_min, "/salaryRange/min"), (&mut draft.pay_rate_max, "/salaryRange/max")] {
+                    if let Some((raw, path)) = at(pointer) {
+                        set_from_raw(field, raw.parse().ok(), bundle, &path, &raw);
+                    }
+                }
+            }
+        }
+        draft
+    }
+}
+
+/// Plain text of Greenhouse `content`, which is HTML with its markup entity-escaped.
+fn html_text(escaped: &str) -> String {
+    let markup = Html::parse_fragment(escaped).root_element().text().collect::<String>();
+    let text = Html::parse_fragment(&markup).root_element().text().collect::<Vec<_>>().join(" ");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[async_trait]
+impl SourceAdapter for JobBoardAdapter {
+    fn source_id(&self) -> &str {
+        &self.source_id
+    }
+
+    fn crawlability(&self) -> Crawlability {
+        Crawlability::Api
+    }
+
+    fn extractor_version(&self) -> &str {
+        &self.extractor_version
+    }
+
+    /// Check that the board still answers with a postings array.
+    async fn health_check(&self, http: &HttpFetcher, ctx: &AdapterContext, _targets: &[ListingTarget]) -> AdapterHealth {
+        let url = self.config.listing_url();
+        probe_json_listing(http, ctx, &self.source_id, &url, &[], self.config.provider.items_pointer()).await
+    }
+
+    /// One page: both providers return every open posting of a board in a single response.
+    async fn fetch_listing(
+        &self,
+        http: &HttpFetcher,
+        ctx: &AdapterContext,
+        _targets: &[ListingTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        let url = self.config.listing_url();
+        let response = http
+            .fetch_bytes_with(ctx.run_id, &self.source_id, &url, RobotsPolicy::Ignore)
+            .await
+            .map_err(|err| AdapterError::fetch_failed(&url, err))?;
+        Ok(vec![FetchedPage::from_response(response, "application/json")])
+    }
+
+    /// One draft per titled posting; a bundle without a raw response falls back to its parsed
+    /// records.
+    fn parse_listing(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        if bundle.source_id != self.source_id {
+            return Err(AdapterError::source_mismatch(bundle, &self.source_id));
+        }
+        let Some(text) = bundle.raw_artifact.inline_text.as_deref() else {
+            return Ok(bundle_to_drafts(bundle));
+        };
+        let body: JsonValue = serde_json::from_str(text)
+            .map_err(|err| AdapterError::ParseFailed(format!("parsing job board response: {err}")))?;
+        let pointer = self.config.provider.items_pointer();
+        let postings = body.pointer(pointer).and_then(JsonValue::as_array).ok_or_else(|| {
+            AdapterError::SchemaMismatch(format!("job board response has no postings array at `{pointer}`"))
+        })?;
+        Ok(postings
+            .iter()
+            .enumerate()
+            .map(|(index, posting)| self.posting_draft(bundle, index, posting))
+            .filter(|draft| draft.title.value.is_some())
+            .collect())
+    }
+
+    async fn fetch_detail(
+        &self,
+        _http: &HttpFetcher,
+        _ctx: &AdapterContext,
+        _targets: &[DetailTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        Ok(Vec::new())
+    }
+
+    fn parse_detail(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        self.parse_listing(bundle)
+    }
+}
@@ -0,0 +1,88 @@
+//! Structural fingerprints of listing pages, for spotting layout changes that leave an adapter
+//! "succeeding" with empty fields.
+
+use std::collections::BTreeSet;
+
+use rhof_storage::ArtifactStore;
+use scraper::{ElementRef, Html};
+use serde::{Deserialize, Serialize};
+
+use crate::FixtureBundle;
+
+/// Elements nested deeper than this are folded into their ancestor at this depth; that far down
+/// is usually content markup rather than page layout.
+const MAX_PATH_DEPTH: usize = 16;
+
+/// The distinct root-to-element paths of an HTML page, each step the tag name plus its sorted
+/// classes (`html>body>div.jobs>ul>li.job`). Text, other attributes and how often a path repeats
+/// are ignored, so a listing with more or fewer items keeps its fingerprint.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayoutFingerprint {
+    /// SHA-256 of the sorted paths; equal hashes mean an unchanged skeleton.
+    pub hash: String,
+    pub paths: BTreeSet<String>,
+}
+
+impl LayoutFingerprint {
+    pub fn of_html(html: &str) -> Self {
+        let document = Html::parse_document(html);
+        let mut paths = BTreeSet::new();
+        collect_paths(document.root_element(), "", 1, &mut paths);
+        let skeleton = paths.iter().map(String::as_str).collect::<Vec<_>>().join("\n");
+        Self { hash: ArtifactStore::sha256_hex(skeleton.as_bytes()), paths }
+    }
+
+    /// The fingerprint of a bundle's raw page, when it is inline HTML.
+    pub fn of_bundle(bundle: &FixtureBundle) -> Option<Self> {
+        let raw = &bundle.raw_artifact;
+        if !raw.content_type.contains("html") {
+            return None;
+        }
+        raw.inline_text.as_deref().map(Self::of_html)
+    }
+
+    /// Share (0-100) of the paths across both pages that only one of them has.
+    pub fn drift_pct(&self, other: &Self) -> f64 {
+        let union = self.paths.union(&other.paths).count();
+        if union == 0 {
+            return 0.0;
+        }
+        let shared = self.paths.intersection(&other.paths).count();
+        (union - shared) as f64 * 100.0 / union as f64
+    }
+}
+
+fn collect_paths(element: ElementRef<'_>, parent: &str, depth: usize, paths: &mut BTreeSet<String>) {
+    let mut classes = element.value().classes().collect::<Vec<_>>();
+    classes.sort_unstable();
+    let mut path = if parent.is_empty() { String::new() } else { format!("{parent}>") };
+    path.push_str(element.value().name());
+    for class in classes {
+        path.push('.');
+        path.push_str(class);
+    }
+    if depth < MAX_PATH_DEPTH {
+        for child in element.children().filter_map(ElementRef::wrap) {
+            collect_paths(child, &path, depth + 1, paths);
+        }
+    }
+    paths.insert(path);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprints_ignore_content_but_not_structure() {
+        let two = LayoutFingerprint::of_html(r#"<ul class="jobs"><li class="job"><a>Rater</a></li><li class="job"><a>Tagger</a></li></ul>"#);
+        let one = LayoutFingerprint::of_html(r#"<ul class="jobs"><li class="job"><a>Transcriber</a></li></ul>"#);
+        assert_eq!(two, one);
+        assert!(two.paths.contains("html>body>ul.jobs>li.job>a"));
+
+        let moved = LayoutFingerprint::of_html(r#"<div class="cards"><article class="card"><h2>Rater</h2></article></div>"#);
+        assert_ne!(moved.hash, one.hash);
+        assert!(moved.drift_pct(&one) > 50.0, "{}", moved.drift_pct(&one));
+        assert_eq!(one.drift_pct(&one), 0.0);
+    }
+}
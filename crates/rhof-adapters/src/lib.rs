@@ -1,21 +1,45 @@
 //! Source adapter contracts + fixture-first adapter implementations.
 
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, PoisonError, RwLock};
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use rhof_core::{EvidenceRef, Field, OpportunityDraft};
-use rhof_storage::HttpFetcher;
-use scraper::{Html, Selector};
+use rhof_storage::{extension_for_content_type, ArtifactStore, FetchError, FetchedResponse, HttpFetcher, RobotsPolicy};
+use scraper::{ElementRef, Html, Selector};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use thiserror::Error;
 use uuid::Uuid;
 
+pub mod charset;
+pub mod generated;
+pub mod generic_json;
+pub mod imap;
+pub mod job_board;
+pub mod layout;
+pub mod manual_entry;
+pub mod rss;
+
+pub use charset::decode_text;
+pub use generic_json::{json_path_to_pointer, GenericJsonAdapter, JsonFieldMap, JsonMapping, JSON_MAPPING_FILE};
+pub use job_board::{JobBoardAdapter, JobBoardConfig, JobBoardProvider};
+pub use manual_entry::{
+    load_manual_entries_bundle, validate_manual_entries, ManualCommitment, ManualEntriesFile, ManualEntry,
+    ManualEntryAdapter, ManualPayModel, MANUAL_ENTRIES_FILE,
+};
+pub use layout::LayoutFingerprint;
+pub use rss::{parse_feed_items, rss_feed_adapter, upwork_rss_adapter, weworkremotely_adapter, FeedFlavor, FeedItem, RssFeedAdapter};
+
 pub const CRATE_NAME: &str = "rhof-adapters";
 
+/// Tag the pipeline adds to drafts from adapters that [require review](SourceAdapter::requires_review).
+pub const MANUAL_REVIEW_TAG: &str = "manual-review";
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Crawlability {
     PublicHtml,
@@ -31,17 +55,142 @@ pub struct FetchedPage {
     pub content_type: String,
     pub body: Vec<u8>,
     pub fetched_at: DateTime<Utc>,
+    /// A conditional fetch came back `304 Not Modified`: `body` is empty and the page is
+    /// unchanged since the run that stored its validators.
+    #[serde(default)]
+    pub not_modified: bool,
+}
+
+/// Server content types that say nothing about what a page is; the adapter's own is kept instead.
+const GENERIC_CONTENT_TYPES: [&str; 3] = ["application/octet-stream", "binary/octet-stream", "text/plain"];
+
+impl FetchedPage {
+    /// A page from a live response. `content_type` is what the adapter reads the page as; the
+    /// server's own `Content-Type` (charset included) replaces it unless it is missing or
+    /// generic, in which case only the server's charset is added, so the page still transcodes
+    /// correctly.
+    pub fn from_response(response: FetchedResponse, content_type: &str) -> Self {
+        let content_type = match (response.mime_type(), response.charset()) {
+            (Some(mime), _) if !GENERIC_CONTENT_TYPES.contains(&mime.as_str()) => {
+                response.content_type().unwrap_or(content_type).to_string()
+            }
+            (_, Some(charset)) => format!("{content_type}; charset={charset}"),
+            _ => content_type.to_string(),
+        };
+        Self {
+            not_modified: response.is_not_modified(),
+            url: response.final_url,
+            content_type,
+            body: response.body,
+            fetched_at: Utc::now(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AdapterContext {
     pub run_id: Uuid,
     pub fetched_at: DateTime<Utc>,
+    /// Skip the robots.txt check for this source's live fetches (per-source override).
+    #[serde(default)]
+    pub ignore_robots: bool,
+}
+
+impl AdapterContext {
+    fn robots(&self) -> RobotsPolicy {
+        if self.ignore_robots {
+            RobotsPolicy::Ignore
+        } else {
+            RobotsPolicy::Respect
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ListingTarget {
     pub url: String,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// Placeholder in a listing URL that is replaced by the page number (1, 2, ...).
+pub const PAGE_PLACEHOLDER: &str = "{page}";
+
+pub const DEFAULT_MAX_LISTING_PAGES: usize = 10;
+
+/// Expand a listing URL template: every `{name}` placeholder other than [`PAGE_PLACEHOLDER`] is
+/// replaced by each of its `variables` values in turn, giving one URL per combination (ordered
+/// by placeholder, then value). Values are inserted verbatim. Fails on a placeholder without
+/// values.
+pub fn expand_listing_url(template: &str, variables: &BTreeMap<String, Vec<String>>) -> Result<Vec<String>, String> {
+    let mut urls = vec![template.to_string()];
+    for name in url_placeholders(template) {
+        if format!("{{{name}}}") == PAGE_PLACEHOLDER {
+            continue;
+        }
+        let values = variables
+            .get(name)
+            .filter(|values| !values.is_empty())
+            .ok_or_else(|| format!("listing URL `{template}` uses `{{{name}}}` but variables has no values for `{name}`"))?;
+        let placeholder = format!("{{{name}}}");
+        urls = urls
+            .iter()
+            .flat_map(|url| values.iter().map(|value| url.replace(&placeholder, value)))
+            .collect();
+    }
+    Ok(urls)
+}
+
+/// Names of the `{name}` placeholders in `template`, first occurrence first.
+pub fn url_placeholders(template: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        rest = &rest[open + 1..];
+        let Some(close) = rest.find('}') else {
+            break;
+        };
+        let name = &rest[..close];
+        if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') && !names.contains(&name) {
+            names.push(name);
+        }
+        rest = &rest[close + 1..];
+    }
+    names
+}
+
+/// How `fetch_listing` walks a multi-page listing. A listing URL containing [`PAGE_PLACEHOLDER`]
+/// is fetched for pages 1, 2, ... until a page 404s or repeats the previous one; otherwise
+/// `next_selector` (if set) is followed from page to page. Either way at most `max_pages` pages
+/// are fetched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Pagination {
+    /// CSS selector of the "next page" link; its `href` is resolved against the current page.
+    pub next_selector: Option<String>,
+    pub max_pages: usize,
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self {
+            next_selector: None,
+            max_pages: DEFAULT_MAX_LISTING_PAGES,
+        }
+    }
+}
+
+impl Pagination {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_pages == 0 {
+            return Err("pagination.max_pages must be at least 1".to_string());
+        }
+        if let Some(selector) = &self.next_selector {
+            Selector::parse(selector)
+                .map_err(|err| format!("invalid pagination.next_selector `{selector}`: {err}"))?;
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -51,16 +200,209 @@ pub struct DetailTarget {
 
 #[derive(Debug, Error)]
 pub enum AdapterError {
+    /// A live fetch failed. `retryable` when the same fetch may succeed later (timeouts,
+    /// connection errors, `429` and `5xx`); a `404` or a robots.txt refusal is not.
+    #[error("fetching {url}: {error:#}")]
+    FetchFailed { url: String, retryable: bool, error: anyhow::Error },
+    /// The fetched or stored payload is not well-formed (bad JSON, XML or CSV, an invalid selector).
+    #[error("{0}")]
+    ParseFailed(String),
+    /// The payload parsed but no longer has the shape the adapter expects, e.g. a missing column
+    /// or items array: the source changed its layout.
+    #[error("{0}")]
+    SchemaMismatch(String),
+    /// A bundle was handed to the adapter of another source.
+    #[error("bundle source_id={bundle} does not match adapter source_id={adapter}")]
+    SourceMismatch { bundle: String, adapter: String },
     #[error("{0}")]
     Message(String),
     #[error(transparent)]
     Anyhow(#[from] anyhow::Error),
 }
 
+/// What the sync pipeline does with a source whose adapter failed; see [`AdapterError::action`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureAction {
+    /// Try the source again; the failure is likely transient.
+    Retry,
+    /// Record the failure and move on; retrying now would fail the same way.
+    Skip,
+    /// The adapter no longer understands the source and needs a code or selector fix.
+    Maintenance,
+}
+
+impl AdapterError {
+    /// A failed fetch of `url`, retryable when [`FetchError::is_retryable`] says so.
+    pub fn fetch_failed(url: impl Into<String>, err: FetchError) -> Self {
+        Self::FetchFailed { url: url.into(), retryable: err.is_retryable(), error: err.into() }
+    }
+
+    pub fn source_mismatch(bundle: &FixtureBundle, adapter_source_id: &str) -> Self {
+        Self::SourceMismatch { bundle: bundle.source_id.clone(), adapter: adapter_source_id.to_string() }
+    }
+
+    pub fn action(&self) -> FailureAction {
+        match self {
+            Self::FetchFailed { retryable: true, .. } => FailureAction::Retry,
+            Self::ParseFailed(_) | Self::SchemaMismatch(_) | Self::SourceMismatch { .. } => FailureAction::Maintenance,
+            Self::FetchFailed { .. } | Self::Message(_) | Self::Anyhow(_) => FailureAction::Skip,
+        }
+    }
+
+    /// The [`FailureAction`] of the first `AdapterError` in `err`'s chain; `None` when the
+    /// failure did not come from an adapter (config, storage, ...).
+    pub fn action_of(err: &anyhow::Error) -> Option<FailureAction> {
+        err.chain()
+            .find_map(|cause| cause.downcast_ref::<AdapterError>())
+            .map(AdapterError::action)
+    }
+
+    fn fetch_error(&self) -> Option<&FetchError> {
+        match self {
+            Self::FetchFailed { error, .. } | Self::Anyhow(error) => error.downcast_ref::<FetchError>(),
+            _ => None,
+        }
+    }
+
+    /// Whether a live fetch was refused because the host's robots.txt disallows the URL.
+    pub fn is_blocked_by_robots(&self) -> bool {
+        matches!(self.fetch_error(), Some(FetchError::BlockedByRobots { .. }))
+    }
+}
+
+/// What an adapter extracts with: its current extractor version and the bundle schema it reads.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtractorManifest {
+    pub source_id: String,
+    pub extractor_version: String,
+    pub crawlability: Crawlability,
+    pub bundle_schema_version: u32,
+}
+
+/// Outcome of [`SourceAdapter::health_check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Healthy,
+    /// The listing page loaded but the sentinel no longer matches (layout change, empty page).
+    SentinelMissing,
+    /// The listing page could not be fetched (network error, HTTP error, robots.txt, credentials).
+    Unreachable,
+    /// Nothing to check: the adapter does not fetch live pages or the source has no listing URL.
+    Skipped,
+}
+
+/// Result of probing one source's listing URL; see [`SourceAdapter::health_check`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdapterHealth {
+    pub source_id: String,
+    pub status: HealthStatus,
+    pub url: Option<String>,
+    pub http_status: Option<u16>,
+    /// CSS selector (or, for API adapters, the items JSON pointer) that was looked for.
+    pub sentinel: Option<String>,
+    pub message: Option<String>,
+    pub checked_at: DateTime<Utc>,
+}
+
+impl AdapterHealth {
+    pub fn skipped(source_id: &str, message: impl Into<String>) -> Self {
+        Self {
+            source_id: source_id.to_string(),
+            status: HealthStatus::Skipped,
+            url: None,
+            http_status: None,
+            sentinel: None,
+            message: Some(message.into()),
+            checked_at: Utc::now(),
+        }
+    }
+
+    /// A probe of `url` for `sentinel` that is healthy until marked otherwise.
+    fn probe(source_id: &str, url: &str, sentinel: &str) -> Self {
+        Self {
+            source_id: source_id.to_string(),
+            status: HealthStatus::Healthy,
+            url: Some(url.to_string()),
+            http_status: None,
+            sentinel: Some(sentinel.to_string()),
+            message: None,
+            checked_at: Utc::now(),
+        }
+    }
+
+    fn fail(mut self, status: HealthStatus, message: impl Into<String>) -> Self {
+        self.status = status;
+        self.message = Some(message.into());
+        self
+    }
+
+    fn unreachable(mut self, err: &FetchError) -> Self {
+        if let FetchError::HttpStatus { status, .. } = err {
+            self.http_status = Some(*status);
+        }
+        self.fail(HealthStatus::Unreachable, err.to_string())
+    }
+
+    /// Whether the source needs attention (skipped sources do not).
+    pub fn is_broken(&self) -> bool {
+        matches!(self.status, HealthStatus::SentinelMissing | HealthStatus::Unreachable)
+    }
+}
+
 #[async_trait]
 pub trait SourceAdapter: Send + Sync {
-    fn source_id(&self) -> &'static str;
+    fn source_id(&self) -> &str;
     fn crawlability(&self) -> Crawlability;
+    /// Version of this adapter's parsing logic, stamped on the bundles it builds from live pages;
+    /// bump it whenever parsed output changes.
+    fn extractor_version(&self) -> &str;
+
+    fn manifest(&self) -> ExtractorManifest {
+        ExtractorManifest {
+            source_id: self.source_id().to_string(),
+            extractor_version: self.extractor_version().to_string(),
+            crawlability: self.crawlability(),
+            bundle_schema_version: FIXTURE_BUNDLE_SCHEMA_VERSION,
+        }
+    }
+
+    /// Whether every draft from this adapter must go to manual review (e.g. links scraped from
+    /// newsletters); the pipeline marks them `review_required` and tags them [`MANUAL_REVIEW_TAG`].
+    fn requires_review(&self) -> bool {
+        false
+    }
+
+    /// CSS selector that matches on every healthy listing page (e.g. the listing items); `None`
+    /// for adapters that do not fetch live HTML, which [`health_check`](Self::health_check) skips.
+    fn sentinel_selector(&self) -> Option<&str> {
+        None
+    }
+
+    /// Cheap probe for scheduled health jobs: fetch only the first page of the first listing
+    /// target and check that [`sentinel_selector`](Self::sentinel_selector) still matches, so a
+    /// dead URL or changed layout is flagged before a full sync relies on it.
+    async fn health_check(&self, http: &HttpFetcher, ctx: &AdapterContext, targets: &[ListingTarget]) -> AdapterHealth {
+        let Some(sentinel) = self.sentinel_selector() else {
+            return AdapterHealth::skipped(self.source_id(), "adapter does not fetch live pages");
+        };
+        let Some(url) = health_check_url(targets) else {
+            return AdapterHealth::skipped(self.source_id(), "source has no listing URL");
+        };
+        let health = AdapterHealth::probe(self.source_id(), &url, sentinel);
+        let response = match http.fetch_bytes_with(ctx.run_id, self.source_id(), &url, ctx.robots()).await {
+            Ok(response) => response,
+            Err(err) => return health.unreachable(&err),
+        };
+        let health = AdapterHealth { http_status: Some(response.status.as_u16()), ..health };
+        let document = Html::parse_document(&decode_text(&response.body, "text/html"));
+        match Selector::parse(sentinel) {
+            Ok(selector) if document.select(&selector).next().is_some() => health,
+            Ok(_) => health.fail(HealthStatus::SentinelMissing, format!("`{sentinel}` matched nothing")),
+            Err(err) => health.fail(HealthStatus::SentinelMissing, format!("invalid sentinel `{sentinel}`: {err}")),
+        }
+    }
 
     async fn fetch_listing(
         &self,
@@ -71,6 +413,26 @@ pub trait SourceAdapter: Send + Sync {
 
     fn parse_listing(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError>;
 
+    /// Parse every page of a paginated listing (one bundle per fetched page) and merge the drafts,
+    /// dropping a draft already seen on an earlier page (same detail URL, apply URL or external id).
+    fn parse_listing_pages(&self, bundles: &[FixtureBundle]) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        let mut seen = HashSet::new();
+        let mut merged = Vec::new();
+        for bundle in bundles {
+            for draft in self.parse_listing(bundle)? {
+                let identity = draft
+                    .detail_url
+                    .clone()
+                    .or_else(|| draft.apply_url.value.clone())
+                    .or_else(|| draft.external_id.clone().map(|id| format!("id:{id}")));
+                if identity.is_none_or(|identity| seen.insert(identity)) {
+                    merged.push(draft);
+                }
+            }
+        }
+        Ok(merged)
+    }
+
     async fn fetch_detail(
         &self,
         _http: &HttpFetcher,
@@ -83,13 +445,21 @@ pub trait SourceAdapter: Send + Sync {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FixtureBundle {
+    /// Layout version; see [`migrate_fixture_bundle`].
+    #[serde(default = "current_bundle_schema_version")]
+    pub schema_version: u32,
     pub fixture_id: String,
     pub source_id: String,
     pub crawlability: Crawlability,
     pub captured_from_url: String,
     pub fetched_at: DateTime<Utc>,
     pub extractor_version: String,
+    /// The page at `captured_from_url`.
     pub raw_artifact: FixtureRawArtifact,
+    /// Further pages of the same capture, e.g. more listing pages or the detail pages its records
+    /// link to, each keyed by role and URL.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub artifacts: Vec<FixtureArtifact>,
     pub parsed_records: Vec<FixtureParsedRecord>,
     pub evidence_coverage_percent: f64,
     pub notes: Option<String>,
@@ -103,11 +473,66 @@ pub struct FixtureRawArtifact {
     pub sha256: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtifactRole {
+    Listing,
+    Detail,
+}
+
+impl ArtifactRole {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Listing => "listing",
+            Self::Detail => "detail",
+        }
+    }
+}
+
+/// One of a bundle's [`artifacts`](FixtureBundle::artifacts): a raw page plus where it came from.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixtureArtifact {
+    pub role: ArtifactRole,
+    pub url: String,
+    #[serde(flatten)]
+    pub raw: FixtureRawArtifact,
+}
+
+impl FixtureBundle {
+    /// The artifact fetched from `url`, if the bundle has one besides `raw_artifact`.
+    pub fn artifact_for_url(&self, url: &str) -> Option<&FixtureArtifact> {
+        self.artifacts.iter().find(|artifact| artifact.url == url)
+    }
+
+    /// One of the bundle's `artifacts` as a bundle of its own, without parsed records, so an
+    /// adapter parses it like a live page and its evidence points at that artifact's raw id.
+    pub fn artifact_bundle(&self, artifact: &FixtureArtifact) -> FixtureBundle {
+        FixtureBundle {
+            fixture_id: format!("{}:{}:{}", self.fixture_id, artifact.role.as_str(), artifact.url),
+            captured_from_url: artifact.url.clone(),
+            raw_artifact: artifact.raw.clone(),
+            artifacts: Vec::new(),
+            parsed_records: match artifact.role {
+                ArtifactRole::Listing => Vec::new(),
+                ArtifactRole::Detail => vec![FixtureParsedRecord {
+                    detail_url: Some(artifact.url.clone()),
+                    ..FixtureParsedRecord::default()
+                }],
+            },
+            evidence_coverage_percent: 0.0,
+            notes: None,
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FixtureParsedRecord {
     pub title: FixtureField<String>,
     pub description: FixtureField<String>,
     pub pay_model: FixtureField<String>,
+    #[serde(default)]
+    pub pay_unit: FixtureField<String>,
     pub pay_rate_min: FixtureField<f64>,
     pub pay_rate_max: FixtureField<f64>,
     pub currency: FixtureField<String>,
@@ -120,13 +545,46 @@ pub struct FixtureParsedRecord {
     pub requirements: FixtureField<Vec<String>>,
     pub listing_url: Option<String>,
     pub detail_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+}
+
+impl FixtureParsedRecord {
+    /// `(field name, populated, selector_or_pointer, artifact_url)` for every evidenced field; the
+    /// first five are the canonical fields evidence coverage is measured over.
+    fn evidence_fields(&self) -> [EvidenceFieldEntry<'_>; 14] {
+        fn entry<'a, T>(name: &'static str, field: &'a FixtureField<T>) -> EvidenceFieldEntry<'a> {
+            (name, field.value.is_some(), field.selector_or_pointer.as_str(), field.artifact_url.as_deref())
+        }
+        [
+            entry("title", &self.title),
+            entry("description", &self.description),
+            entry("pay_model", &self.pay_model),
+            entry("currency", &self.currency),
+            entry("apply_url", &self.apply_url),
+            entry("pay_unit", &self.pay_unit),
+            entry("pay_rate_min", &self.pay_rate_min),
+            entry("pay_rate_max", &self.pay_rate_max),
+            entry("min_hours_per_week", &self.min_hours_per_week),
+            entry("verification_requirements", &self.verification_requirements),
+            entry("geo_constraints", &self.geo_constraints),
+            entry("one_off_vs_ongoing", &self.one_off_vs_ongoing),
+            entry("payment_methods", &self.payment_methods),
+            entry("requirements", &self.requirements),
+        ]
+    }
 }
 
+type EvidenceFieldEntry<'a> = (&'static str, bool, &'a str, Option<&'a str>);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FixtureField<T> {
     pub value: Option<T>,
     pub selector_or_pointer: String,
     pub snippet: String,
+    /// URL of the bundle artifact the evidence was found in; unset means `raw_artifact`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifact_url: Option<String>,
 }
 
 impl<T> Default for FixtureField<T> {
@@ -135,24 +593,92 @@ impl<T> Default for FixtureField<T> {
             value: None,
             selector_or_pointer: String::new(),
             snippet: String::new(),
+            artifact_url: None,
+        }
+    }
+}
+
+/// Current [`FixtureBundle`] layout. Bundles written before `schema_version` existed are version 1.
+pub const FIXTURE_BUNDLE_SCHEMA_VERSION: u32 = 3;
+
+fn current_bundle_schema_version() -> u32 {
+    FIXTURE_BUNDLE_SCHEMA_VERSION
+}
+
+/// Upgrade steps; entry `n` upgrades a version `n + 1` bundle to version `n + 2`.
+const BUNDLE_MIGRATIONS: [fn(&mut serde_json::Map<String, JsonValue>); 2] =
+    [migrate_bundle_v1_to_v2, migrate_bundle_v2_to_v3];
+
+/// Version 1 bundles were written by hand and could leave out `notes`,
+/// `evidence_coverage_percent` and the null `raw_artifact` keys, which version 2 requires.
+fn migrate_bundle_v1_to_v2(bundle: &mut serde_json::Map<String, JsonValue>) {
+    bundle.entry("notes").or_insert(JsonValue::Null);
+    bundle.entry("evidence_coverage_percent").or_insert(JsonValue::from(0.0));
+    if let Some(JsonValue::Object(raw)) = bundle.get_mut("raw_artifact") {
+        for key in ["path", "inline_text", "sha256"] {
+            raw.entry(key).or_insert(JsonValue::Null);
         }
     }
 }
 
+/// Version 3 added `artifacts` and per-field `artifact_url`; a version 2 bundle is its single
+/// `raw_artifact`, so it only needs the empty list.
+fn migrate_bundle_v2_to_v3(bundle: &mut serde_json::Map<String, JsonValue>) {
+    bundle.entry("artifacts").or_insert(JsonValue::Array(Vec::new()));
+}
+
+/// Upgrade a bundle's JSON to [`FIXTURE_BUNDLE_SCHEMA_VERSION`]. A bundle from a newer schema is
+/// rejected rather than parsed with fields this build may misread.
+pub fn migrate_fixture_bundle(mut value: JsonValue) -> Result<JsonValue> {
+    let bundle = value.as_object_mut().context("fixture bundle must be a JSON object")?;
+    let version = match bundle.get("schema_version") {
+        None => 1,
+        Some(version) => version
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .filter(|v| *v >= 1)
+            .with_context(|| format!("invalid schema_version {version}"))?,
+    };
+    if version > FIXTURE_BUNDLE_SCHEMA_VERSION {
+        anyhow::bail!(
+            "fixture bundle schema_version {version} is newer than this build supports ({FIXTURE_BUNDLE_SCHEMA_VERSION}); upgrade rhof before loading it"
+        );
+    }
+    for migrate in &BUNDLE_MIGRATIONS[version as usize - 1..] {
+        migrate(bundle);
+    }
+    bundle.insert("schema_version".to_string(), JsonValue::from(FIXTURE_BUNDLE_SCHEMA_VERSION));
+    Ok(value)
+}
+
 pub fn load_fixture_bundle(path: impl AsRef<Path>) -> Result<FixtureBundle> {
     let path = path.as_ref();
-    let mut bundle: FixtureBundle = read_json_file(path)?;
+    let mut bundle = read_bundle_file(path)?;
     hydrate_inline_raw_artifact(path, &mut bundle)?;
     Ok(bundle)
 }
 
+/// Load a manual source's bundle: a `sample.json` fixture, an `entries.json` wrapped by
+/// [`load_manual_entries_bundle`], or a `listings.csv` wrapped by [`load_manual_csv_bundle`].
 pub fn load_manual_fixture_bundle(path: impl AsRef<Path>) -> Result<FixtureBundle> {
     let path = path.as_ref();
-    let mut bundle: FixtureBundle = read_json_file(path)?;
+    if path.extension().is_some_and(|ext| ext == "csv") {
+        return load_manual_csv_bundle(path);
+    }
+    if path.file_name().is_some_and(|name| name == MANUAL_ENTRIES_FILE) {
+        return load_manual_entries_bundle(path);
+    }
+    let mut bundle = read_bundle_file(path)?;
     hydrate_inline_raw_artifact(path, &mut bundle)?;
     Ok(bundle)
 }
 
+fn read_bundle_file(path: &Path) -> Result<FixtureBundle> {
+    let value: JsonValue = read_json_file(path)?;
+    let value = migrate_fixture_bundle(value).with_context(|| format!("migrating {}", path.display()))?;
+    serde_json::from_value(value).with_context(|| format!("parsing {}", path.display()))
+}
+
 fn read_json_file<T: DeserializeOwned>(path: impl AsRef<Path>) -> Result<T> {
     let path = path.as_ref();
     let data = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
@@ -160,10 +686,18 @@ fn read_json_file<T: DeserializeOwned>(path: impl AsRef<Path>) -> Result<T> {
 }
 
 fn hydrate_inline_raw_artifact(bundle_path: &Path, bundle: &mut FixtureBundle) -> Result<()> {
-    if bundle.raw_artifact.inline_text.is_some() {
+    hydrate_raw_artifact(bundle_path, &mut bundle.raw_artifact)?;
+    for artifact in &mut bundle.artifacts {
+        hydrate_raw_artifact(bundle_path, &mut artifact.raw)?;
+    }
+    Ok(())
+}
+
+fn hydrate_raw_artifact(bundle_path: &Path, raw_artifact: &mut FixtureRawArtifact) -> Result<()> {
+    if raw_artifact.inline_text.is_some() {
         return Ok(());
     }
-    let Some(rel_path) = &bundle.raw_artifact.path else {
+    let Some(rel_path) = &raw_artifact.path else {
         return Ok(());
     };
     let raw_path = bundle_path
@@ -173,12 +707,97 @@ fn hydrate_inline_raw_artifact(bundle_path: &Path, bundle: &mut FixtureBundle) -
     if !raw_path.exists() {
         return Ok(());
     }
-    let raw = fs::read_to_string(&raw_path)
-        .with_context(|| format!("reading fixture raw artifact {}", raw_path.display()))?;
-    bundle.raw_artifact.inline_text = Some(raw);
+    let raw = fs::read(&raw_path).with_context(|| format!("reading fixture raw artifact {}", raw_path.display()))?;
+    raw_artifact.inline_text = Some(decode_text(&raw, &raw_artifact.content_type));
     Ok(())
 }
 
+/// Wrap a live detail page in a bundle with one empty parsed record, so `parse_detail` reads every
+/// value (with evidence) from the page itself. The fixture id is content-addressed, which keeps
+/// the raw artifact id stable while the page is unchanged.
+pub fn detail_page_bundle(
+    source_id: &str,
+    crawlability: Crawlability,
+    extractor_version: &str,
+    page: &FetchedPage,
+) -> FixtureBundle {
+    let mut bundle = live_page_bundle("detail", source_id, crawlability, extractor_version, page);
+    bundle.parsed_records = vec![FixtureParsedRecord {
+        detail_url: Some(page.url.clone()),
+        ..FixtureParsedRecord::default()
+    }];
+    bundle
+}
+
+/// Wrap a live listing page (e.g. one API response) in a bundle without parsed records, so
+/// `parse_listing` reads every draft from the page itself.
+pub fn listing_page_bundle(
+    source_id: &str,
+    crawlability: Crawlability,
+    extractor_version: &str,
+    page: &FetchedPage,
+) -> FixtureBundle {
+    live_page_bundle("listing", source_id, crawlability, extractor_version, page)
+}
+
+fn live_page_bundle(
+    kind: &str,
+    source_id: &str,
+    crawlability: Crawlability,
+    extractor_version: &str,
+    page: &FetchedPage,
+) -> FixtureBundle {
+    let sha256 = ArtifactStore::sha256_hex(&page.body);
+    FixtureBundle {
+        schema_version: FIXTURE_BUNDLE_SCHEMA_VERSION,
+        fixture_id: format!("{kind}-{sha256}"),
+        source_id: source_id.to_string(),
+        crawlability,
+        captured_from_url: page.url.clone(),
+        fetched_at: page.fetched_at,
+        extractor_version: extractor_version.to_string(),
+        raw_artifact: FixtureRawArtifact {
+            content_type: page.content_type.clone(),
+            path: None,
+            inline_text: Some(decode_text(&page.body, &page.content_type)),
+            sha256: Some(sha256),
+        },
+        artifacts: Vec::new(),
+        parsed_records: Vec::new(),
+        evidence_coverage_percent: 0.0,
+        notes: Some(format!("live {kind} page")),
+    }
+}
+
+fn merge_detail_field<T>(listing: &mut Field<T>, detail: Field<T>, overwrite: bool) {
+    if detail.value.is_some() && (overwrite || listing.value.is_none()) {
+        *listing = detail;
+    }
+}
+
+/// Merge a draft parsed from a detail page into its listing draft. Detail values (and their
+/// evidence) replace the listing's, except `title` and `apply_url`, which only fill gaps because
+/// they identify the listing and feed its canonical key.
+pub fn merge_detail_draft(listing: &mut OpportunityDraft, detail: OpportunityDraft) {
+    if listing.external_id.is_none() {
+        listing.external_id = detail.external_id;
+    }
+    merge_detail_field(&mut listing.title, detail.title, false);
+    merge_detail_field(&mut listing.apply_url, detail.apply_url, false);
+    merge_detail_field(&mut listing.description, detail.description, true);
+    merge_detail_field(&mut listing.pay_model, detail.pay_model, true);
+    merge_detail_field(&mut listing.pay_unit, detail.pay_unit, true);
+    merge_detail_field(&mut listing.pay_rate_min, detail.pay_rate_min, true);
+    merge_detail_field(&mut listing.pay_rate_max, detail.pay_rate_max, true);
+    merge_detail_field(&mut listing.currency, detail.currency, true);
+    merge_detail_field(&mut listing.min_hours_per_week, detail.min_hours_per_week, true);
+    merge_detail_field(&mut listing.verification_requirements, detail.verification_requirements, true);
+    merge_detail_field(&mut listing.geo_constraints, detail.geo_constraints, true);
+    merge_detail_field(&mut listing.one_off_vs_ongoing, detail.one_off_vs_ongoing, true);
+    merge_detail_field(&mut listing.payment_methods, detail.payment_methods, true);
+    merge_detail_field(&mut listing.requirements, detail.requirements, true);
+}
+
 pub fn deterministic_raw_artifact_id_for_bundle(bundle: &FixtureBundle) -> Uuid {
     let source = format!(
         "{}:{}:{}",
@@ -193,16 +812,24 @@ pub fn deterministic_raw_artifact_id_for_bundle(bundle: &FixtureBundle) -> Uuid
     Uuid::new_v5(&Uuid::NAMESPACE_URL, source.as_bytes())
 }
 
+/// Evidence naming one of the bundle's `artifacts` points at that artifact's raw id and URL, the
+/// same ones [`FixtureBundle::artifact_bundle`] gives it when it is stored.
 fn fixture_field_to_core<T: Clone>(
     fixture: &FixtureField<T>,
     bundle: &FixtureBundle,
 ) -> Field<T> {
+    let artifact_bundle = fixture
+        .artifact_url
+        .as_deref()
+        .and_then(|url| bundle.artifact_for_url(url))
+        .map(|artifact| bundle.artifact_bundle(artifact));
+    let evidence_bundle = artifact_bundle.as_ref().unwrap_or(bundle);
     match &fixture.value {
         Some(value) => Field::with_value_and_evidence(
             value.clone(),
             EvidenceRef {
-                raw_artifact_id: deterministic_raw_artifact_id_for_bundle(bundle),
-                source_url: bundle.captured_from_url.clone(),
+                raw_artifact_id: deterministic_raw_artifact_id_for_bundle(evidence_bundle),
+                source_url: evidence_bundle.captured_from_url.clone(),
                 selector_or_pointer: fixture.selector_or_pointer.clone(),
                 snippet: fixture.snippet.clone(),
                 fetched_at: bundle.fetched_at,
@@ -221,11 +848,14 @@ fn bundle_to_drafts(bundle: &FixtureBundle) -> Vec<OpportunityDraft> {
             source_id: bundle.source_id.clone(),
             listing_url: record.listing_url.clone(),
             detail_url: record.detail_url.clone(),
+            external_id: record.external_id.clone(),
+            language: None,
             fetched_at: bundle.fetched_at,
             extractor_version: bundle.extractor_version.clone(),
             title: fixture_field_to_core(&record.title, bundle),
             description: fixture_field_to_core(&record.description, bundle),
             pay_model: fixture_field_to_core(&record.pay_model, bundle),
+            pay_unit: fixture_field_to_core(&record.pay_unit, bundle),
             pay_rate_min: fixture_field_to_core(&record.pay_rate_min, bundle),
             pay_rate_max: fixture_field_to_core(&record.pay_rate_max, bundle),
             currency: fixture_field_to_core(&record.currency, bundle),
@@ -247,12 +877,14 @@ fn bundle_to_drafts(bundle: &FixtureBundle) -> Vec<OpportunityDraft> {
 struct HtmlTitleLinkFixtureAdapter {
     source_id: &'static str,
     crawlability: Crawlability,
+    extractor_version: &'static str,
 }
 
 #[derive(Debug, Clone, Copy)]
 struct JsonTitleApplyFixtureAdapter {
     source_id: &'static str,
     crawlability: Crawlability,
+    extractor_version: &'static str,
 }
 
 fn override_field_value<T>(field: &mut Field<T>, value: Option<T>) {
@@ -271,7 +903,7 @@ fn text_or_none(value: String) -> Option<String> {
 }
 
 fn select_first_text(document: &Html, selector: &str) -> Result<Option<String>, AdapterError> {
-    let sel = Selector::parse(selector).map_err(|e| AdapterError::Message(e.to_string()))?;
+    let sel = Selector::parse(selector).map_err(|e| AdapterError::ParseFailed(e.to_string()))?;
     Ok(document
         .select(&sel)
         .next()
@@ -279,7 +911,7 @@ fn select_first_text(document: &Html, selector: &str) -> Result<Option<String>,
 }
 
 fn select_all_texts(document: &Html, selector: &str) -> Result<Vec<String>, AdapterError> {
-    let sel = Selector::parse(selector).map_err(|e| AdapterError::Message(e.to_string()))?;
+    let sel = Selector::parse(selector).map_err(|e| AdapterError::ParseFailed(e.to_string()))?;
     Ok(document
         .select(&sel)
         .filter_map(|n| text_or_none(n.text().collect::<String>()))
@@ -287,7 +919,7 @@ fn select_all_texts(document: &Html, selector: &str) -> Result<Vec<String>, Adap
 }
 
 fn select_first_attr(document: &Html, selector: &str, attr: &str) -> Result<Option<String>, AdapterError> {
-    let sel = Selector::parse(selector).map_err(|e| AdapterError::Message(e.to_string()))?;
+    let sel = Selector::parse(selector).map_err(|e| AdapterError::ParseFailed(e.to_string()))?;
     Ok(document
         .select(&sel)
         .next()
@@ -295,11 +927,14 @@ fn select_first_attr(document: &Html, selector: &str, attr: &str) -> Result<Opti
         .and_then(|s| text_or_none(s.to_string())))
 }
 
+/// Numbers in `text`. A comma followed by exactly three digits is a thousands separator
+/// (`₹1,500`); one followed by one or two digits is a decimal comma (`12,50 €`).
 fn extract_numbers(text: &str) -> Vec<f64> {
     let mut out = Vec::new();
     let mut current = String::new();
     let mut seen_dot = false;
-    for ch in text.chars() {
+    let chars = text.chars().collect::<Vec<_>>();
+    for (index, &ch) in chars.iter().enumerate() {
         if ch.is_ascii_digit() {
             current.push(ch);
             continue;
@@ -309,6 +944,17 @@ fn extract_numbers(text: &str) -> Vec<f64> {
             seen_dot = true;
             continue;
         }
+        if ch == ',' && !seen_dot && !current.is_empty() {
+            let digits = chars[index + 1..].iter().take_while(|c| c.is_ascii_digit()).count();
+            if digits == 3 {
+                continue;
+            }
+            if digits == 1 || digits == 2 {
+                current.push('.');
+                seen_dot = true;
+                continue;
+            }
+        }
         if !current.is_empty() {
             if let Ok(v) = current.parse::<f64>() {
                 out.push(v);
@@ -325,7 +971,16 @@ fn extract_numbers(text: &str) -> Vec<f64> {
     out
 }
 
-fn parse_pay_fields(pay_text: &str) -> (Option<String>, Option<f64>, Option<f64>, Option<String>) {
+/// What a pay text says: model, rate range, currency and the unit one rate pays for.
+struct ParsedPay {
+    model: Option<String>,
+    rate_min: Option<f64>,
+    rate_max: Option<f64>,
+    currency: Option<String>,
+    unit: Option<String>,
+}
+
+fn parse_pay_fields(pay_text: &str) -> ParsedPay {
     let lower = pay_text.to_ascii_lowercase();
     let pay_model = if lower.contains("per task") || lower.contains("task-based") {
         Some("task-based".to_string())
@@ -339,12 +994,76 @@ fn parse_pay_fields(pay_text: &str) -> (Option<String>, Option<f64>, Option<f64>
     let nums = extract_numbers(pay_text);
     let pay_rate_min = nums.first().copied();
     let pay_rate_max = nums.get(1).copied().or(pay_rate_min);
-    let currency = if lower.contains("usd") || pay_text.contains('$') {
-        Some("USD".to_string())
-    } else {
-        None
-    };
-    (pay_model, pay_rate_min, pay_rate_max, currency)
+    ParsedPay {
+        model: pay_model,
+        rate_min: pay_rate_min,
+        rate_max: pay_rate_max,
+        currency: detect_currency(pay_text).map(str::to_string),
+        unit: detect_pay_unit(pay_text).map(str::to_string),
+    }
+}
+
+/// Pay units and the phrases that name them.
+const PAY_UNITS: &[(&str, &[&str])] = &[
+    ("hour", &["/hr", "/hour", "per hour", "an hour", "hourly", "per hr"]),
+    ("minute", &["/min", "per minute", "a minute"]),
+    ("word", &["/word", "per word", "a word"]),
+    ("task", &["/task", "per task", "a task", "task-based", "per hit", "per item"]),
+    ("study", &["/study", "per study", "a study", "per survey"]),
+    ("week", &["/week", "/wk", "per week", "weekly"]),
+    ("month", &["/month", "/mo", "per month", "monthly"]),
+];
+
+/// The unit named earliest in a pay text, so `$15/hr, paid monthly` is hourly. A bare unit
+/// name (`hours`, `Task`), as APIs tend to return, is recognized too.
+fn detect_pay_unit(pay_text: &str) -> Option<&'static str> {
+    let lower = pay_text.trim().to_lowercase();
+    let bare = lower.trim_end_matches('s');
+    PAY_UNITS
+        .iter()
+        .flat_map(|(unit, phrases)| phrases.iter().filter_map(|phrase| Some((lower.find(phrase)?, *unit))))
+        .min_by_key(|(at, _)| *at)
+        .map(|(_, unit)| unit)
+        .or_else(|| PAY_UNITS.iter().map(|(unit, _)| *unit).find(|unit| *unit == bare))
+}
+
+/// ISO 4217 codes recognized as whole words in pay text.
+const CURRENCY_CODES: &[&str] = &[
+    "USD", "EUR", "GBP", "INR", "CAD", "AUD", "NZD", "CHF", "JPY", "CNY", "SEK", "NOK", "DKK", "PLN", "CZK",
+    "BRL", "MXN", "ZAR", "SGD", "HKD", "PHP", "NGN", "KES",
+];
+
+/// Currency symbols, most specific first so `CA$` is not read as `$`.
+const CURRENCY_SYMBOLS: &[(&str, &str)] = &[
+    ("CA$", "CAD"),
+    ("C$", "CAD"),
+    ("AU$", "AUD"),
+    ("A$", "AUD"),
+    ("NZ$", "NZD"),
+    ("US$", "USD"),
+    ("€", "EUR"),
+    ("£", "GBP"),
+    ("₹", "INR"),
+    ("¥", "JPY"),
+    ("R$", "BRL"),
+    ("$", "USD"),
+];
+
+/// The currency of a pay text: an ISO code wins (`$20 CAD`), then a symbol, then a
+/// spelled-out `euro`.
+fn detect_currency(pay_text: &str) -> Option<&'static str> {
+    let code = pay_text
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .find_map(|word| CURRENCY_CODES.iter().find(|code| word.eq_ignore_ascii_case(code)));
+    if let Some(code) = code {
+        return Some(code);
+    }
+    let upper = pay_text.to_uppercase();
+    if let Some((_, code)) = CURRENCY_SYMBOLS.iter().find(|(symbol, _)| upper.contains(symbol)) {
+        return Some(code);
+    }
+    let lower = pay_text.to_lowercase();
+    lower.contains("euro").then_some("EUR")
 }
 
 fn normalize_duration(value: &str) -> Option<String> {
@@ -390,53 +1109,132 @@ fn apply_extended_html_overrides(bundle: &FixtureBundle, drafts: &mut [Opportuni
 
     let mut applied = false;
     if let Some(t) = title {
-        first.title.value = Some(t);
-        applied = true;
+        applied |= set_from_raw(&mut first.title, Some(t.clone()), bundle, "h1", &t);
     }
     if let Some(url) = apply {
-        first.apply_url.value = Some(url);
-        applied = true;
+        applied |= set_from_raw(&mut first.apply_url, Some(url.clone()), bundle, "a[href]", &url);
     }
     if let Some(desc) = description {
-        first.description.value = Some(desc);
-        applied = true;
+        applied |= set_from_raw(&mut first.description, Some(desc.clone()), bundle, ".job-description, .summary", &desc);
     }
     if let Some(pay) = pay_text.as_deref() {
-        let (pay_model, pay_min, pay_max, currency) = parse_pay_fields(pay);
-        override_field_value(&mut first.pay_model, pay_model);
-        override_field_value(&mut first.pay_rate_min, pay_min);
-        override_field_value(&mut first.pay_rate_max, pay_max);
-        override_field_value(&mut first.currency, currency);
+        let parsed = parse_pay_fields(pay);
+        set_from_raw(&mut first.pay_model, parsed.model, bundle, ".pay", pay);
+        set_from_raw(&mut first.pay_unit, parsed.unit, bundle, ".pay", pay);
+        set_from_raw(&mut first.pay_rate_min, parsed.rate_min, bundle, ".pay", pay);
+        set_from_raw(&mut first.pay_rate_max, parsed.rate_max, bundle, ".pay", pay);
+        set_from_raw(&mut first.currency, parsed.currency, bundle, ".pay", pay);
         applied = true;
     }
     if let Some(hours) = hours_text.as_deref() {
-        override_field_value(&mut first.min_hours_per_week, extract_numbers(hours).first().copied());
+        set_from_raw(&mut first.min_hours_per_week, extract_numbers(hours).first().copied(), bundle, ".hours", hours);
         applied = true;
     }
     if let Some(v) = verification {
-        first.verification_requirements.value = Some(v);
-        applied = true;
+        applied |= set_from_raw(&mut first.verification_requirements, Some(v.clone()), bundle, ".verification", &v);
     }
     if let Some(g) = geo {
-        first.geo_constraints.value = Some(g);
-        applied = true;
+        applied |= set_from_raw(&mut first.geo_constraints, Some(g.clone()), bundle, ".geo", &g);
     }
     if let Some(d) = duration.as_deref() {
-        override_field_value(&mut first.one_off_vs_ongoing, normalize_duration(d));
+        set_from_raw(&mut first.one_off_vs_ongoing, normalize_duration(d), bundle, ".duration", d);
         applied = true;
     }
     if !payment_methods.is_empty() {
-        first.payment_methods.value = Some(payment_methods);
+        let snippet = payment_methods.join(", ");
+        set_from_raw(&mut first.payment_methods, Some(payment_methods), bundle, ".payments", &snippet);
         applied = true;
     }
     if !requirements.is_empty() {
-        first.requirements.value = Some(requirements);
+        let snippet = requirements.join(", ");
+        set_from_raw(&mut first.requirements, Some(requirements), bundle, ".requirements li", &snippet);
         applied = true;
     }
+    applied |= apply_meta_fallbacks(&document, bundle, first)?;
 
     Ok(applied)
 }
 
+/// Page-level meta tags tried, in order, for fields whose selectors found nothing:
+/// `(field, selector, attribute)`, where an empty attribute means the element's text.
+const META_FALLBACKS: [(&str, &str, &str); 6] = [
+    ("title", r#"meta[property="og:title"]"#, "content"),
+    ("title", "title", ""),
+    ("description", r#"meta[property="og:description"]"#, "content"),
+    ("description", r#"meta[name="description"]"#, "content"),
+    ("apply_url", r#"link[rel="canonical"]"#, "href"),
+    ("apply_url", r#"meta[property="og:url"]"#, "content"),
+];
+
+/// Fill a still-empty title, description or apply URL from OpenGraph/meta tags, so a minor
+/// redesign that breaks the selectors doesn't leave the draft empty. Evidence names the tag.
+fn apply_meta_fallbacks(document: &Html, bundle: &FixtureBundle, draft: &mut OpportunityDraft) -> Result<bool, AdapterError> {
+    let mut applied = false;
+    for (field, selector, attr) in META_FALLBACKS {
+        let target = match field {
+            "title" => &mut draft.title,
+            "description" => &mut draft.description,
+            _ => &mut draft.apply_url,
+        };
+        if target.value.is_some() {
+            continue;
+        }
+        let value = if attr.is_empty() {
+            select_first_text(document, selector)?
+        } else {
+            select_first_attr(document, selector, attr)?
+        };
+        let Some(value) = value else {
+            continue;
+        };
+        let resolved = if field == "apply_url" {
+            resolve_href(&bundle.captured_from_url, &value)
+        } else {
+            value.clone()
+        };
+        applied |= set_from_raw(target, Some(resolved), bundle, selector, &value);
+    }
+    Ok(applied)
+}
+
+/// `href` resolved against the page it was found on; left as-is if either doesn't parse.
+fn resolve_href(base: &str, href: &str) -> String {
+    url::Url::parse(base)
+        .and_then(|base| base.join(href))
+        .map(|url| url.to_string())
+        .unwrap_or_else(|_| href.to_string())
+}
+
+/// Longest snippet kept in evidence for a value read straight from a raw artifact.
+const RAW_EVIDENCE_SNIPPET_CHARS: usize = 120;
+
+/// Override `field` with a value read from the bundle's raw artifact. A field the bundle's parsed
+/// record gave no evidence (e.g. a live detail page parsed from a skeleton record) gets evidence
+/// pointing at `selector` in that artifact.
+fn set_from_raw<T>(
+    field: &mut Field<T>,
+    value: Option<T>,
+    bundle: &FixtureBundle,
+    selector: &str,
+    snippet: &str,
+) -> bool {
+    let Some(value) = value else {
+        return false;
+    };
+    if field.evidence.is_none() {
+        field.evidence = Some(EvidenceRef {
+            raw_artifact_id: deterministic_raw_artifact_id_for_bundle(bundle),
+            source_url: bundle.captured_from_url.clone(),
+            selector_or_pointer: selector.to_string(),
+            snippet: snippet.chars().take(RAW_EVIDENCE_SNIPPET_CHARS).collect(),
+            fetched_at: bundle.fetched_at,
+            extractor_version: bundle.extractor_version.clone(),
+        });
+    }
+    field.value = Some(value);
+    true
+}
+
 fn json_str<'a>(value: &'a JsonValue, path: &[&str]) -> Option<&'a str> {
     let mut cur = value;
     for segment in path {
@@ -470,6 +1268,15 @@ fn json_string_vec(value: &JsonValue, path: &[&str]) -> Option<Vec<String>> {
     }
 }
 
+/// Stable id from common API keys; numeric ids are rendered without a fractional part.
+fn json_external_id(value: &JsonValue) -> Option<String> {
+    ["external_id", "id", "job_id", "study_id"].iter().find_map(|key| match value.get(*key)? {
+        JsonValue::String(s) => text_or_none(s.clone()),
+        JsonValue::Number(n) => Some(n.to_string()),
+        _ => None,
+    })
+}
+
 fn apply_extended_json_overrides(bundle: &FixtureBundle, drafts: &mut [OpportunityDraft]) -> Result<bool, AdapterError> {
     let Some(text) = bundle.raw_artifact.inline_text.as_deref() else {
         return Ok(false);
@@ -478,9 +1285,10 @@ fn apply_extended_json_overrides(bundle: &FixtureBundle, drafts: &mut [Opportuni
         return Ok(false);
     };
     let value: JsonValue = serde_json::from_str(text)
-        .map_err(|e| AdapterError::Message(format!("invalid raw JSON fixture: {e}")))?;
+        .map_err(|e| AdapterError::ParseFailed(format!("invalid raw JSON fixture: {e}")))?;
 
     let title = json_str(&value, &["title"]).map(ToString::to_string);
+    let external_id = json_external_id(&value);
     let apply = json_str(&value, &["apply_url"]).map(ToString::to_string);
     let description = json_str(&value, &["description"]).map(ToString::to_string);
     let pay_model = json_str(&value, &["reward", "model"])
@@ -519,6 +1327,10 @@ fn apply_extended_json_overrides(bundle: &FixtureBundle, drafts: &mut [Opportuni
         first.title.value = Some(t);
         applied = true;
     }
+    if let Some(id) = external_id {
+        first.external_id = Some(id);
+        applied = true;
+    }
     if let Some(url) = apply {
         first.apply_url.value = Some(url);
         applied = true;
@@ -577,36 +1389,207 @@ fn parse_title_apply_from_raw_json(bundle: &FixtureBundle) -> Result<Option<Vec<
     Ok(Some(drafts))
 }
 
-#[async_trait]
-impl SourceAdapter for HtmlTitleLinkFixtureAdapter {
-    fn source_id(&self) -> &'static str {
-        self.source_id
+/// Fetch each URL in order through the shared [`HttpFetcher`] (so its concurrency limits, rate
+/// limiting and retries apply), stamping every page with the time its response arrived. A failed
+/// URL fails the whole call.
+async fn fetch_html_pages<'a>(
+    http: &HttpFetcher,
+    ctx: &AdapterContext,
+    source_id: &str,
+    urls: impl Iterator<Item = &'a str> + Send,
+) -> Result<Vec<FetchedPage>, AdapterError> {
+    let mut pages = Vec::new();
+    for url in urls {
+        let response = http
+            .fetch_bytes_with(ctx.run_id, source_id, url, ctx.robots())
+            .await
+            .map_err(|err| AdapterError::fetch_failed(url, err))?;
+        pages.push(FetchedPage::from_response(response, "text/html"));
     }
+    Ok(pages)
+}
 
-    fn crawlability(&self) -> Crawlability {
-        self.crawlability
+/// Health of a JSON listing endpoint (fetched without robots.txt, like every API call): healthy
+/// when `items_pointer` resolves to an array.
+async fn probe_json_listing(
+    http: &HttpFetcher,
+    ctx: &AdapterContext,
+    source_id: &str,
+    url: &str,
+    headers: &[(String, String)],
+    items_pointer: &str,
+) -> AdapterHealth {
+    let health = AdapterHealth::probe(source_id, url, items_pointer);
+    let response = match http
+        .fetch_bytes_with_headers(ctx.run_id, source_id, url, RobotsPolicy::Ignore, headers)
+        .await
+    {
+        Ok(response) => response,
+        Err(err) => return health.unreachable(&err),
+    };
+    let health = AdapterHealth { http_status: Some(response.status.as_u16()), ..health };
+    match serde_json::from_slice::<JsonValue>(&response.body) {
+        Ok(body) if body.pointer(items_pointer).is_some_and(JsonValue::is_array) => health,
+        Ok(_) => health.fail(
+            HealthStatus::SentinelMissing,
+            format!("items pointer `{items_pointer}` does not resolve to an array"),
+        ),
+        Err(err) => health.fail(HealthStatus::SentinelMissing, format!("response is not JSON: {err}")),
     }
+}
 
-    async fn fetch_listing(
-        &self,
-        _http: &HttpFetcher,
-        _ctx: &AdapterContext,
-        _targets: &[ListingTarget],
-    ) -> Result<Vec<FetchedPage>, AdapterError> {
-        Ok(Vec::new())
+/// The URL a health check probes: the first listing target, on page 1 if it is numbered.
+fn health_check_url(targets: &[ListingTarget]) -> Option<String> {
+    targets.first().map(|target| target.url.replace(PAGE_PLACEHOLDER, "1"))
+}
+
+/// Walk one listing target page by page according to its [`Pagination`].
+async fn fetch_listing_pages(
+    http: &HttpFetcher,
+    ctx: &AdapterContext,
+    source_id: &str,
+    target: &ListingTarget,
+) -> Result<Vec<FetchedPage>, AdapterError> {
+    let max_pages = target.pagination.max_pages.max(1);
+    let mut pages: Vec<FetchedPage> = Vec::new();
+
+    if target.url.contains(PAGE_PLACEHOLDER) {
+        for page_no in 1..=max_pages {
+            let url = target.url.replace(PAGE_PLACEHOLDER, &page_no.to_string());
+            let response = match http.fetch_bytes_with(ctx.run_id, source_id, &url, ctx.robots()).await {
+                Ok(response) => response,
+                // Running past the last page is how a numbered listing ends.
+                Err(FetchError::HttpStatus { status: 404, .. }) if page_no > 1 => break,
+                Err(err) => return Err(AdapterError::fetch_failed(url, err)),
+            };
+            // Some sites clamp out-of-range page numbers to the last page instead of 404ing.
+            if !response.is_not_modified() && pages.last().is_some_and(|prev| !prev.not_modified && prev.body == response.body) {
+                break;
+            }
+            pages.push(FetchedPage::from_response(response, "text/html"));
+        }
+        return Ok(pages);
     }
 
-    fn parse_listing(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
-        if bundle.source_id != self.source_id {
-            return Err(AdapterError::Message(format!(
-                "bundle source_id={} does not match adapter source_id={}",
-                bundle.source_id, self.source_id
-            )));
+    let mut visited = HashSet::new();
+    let mut next = Some(target.url.clone());
+    while let Some(url) = next.take() {
+        if pages.len() >= max_pages || !visited.insert(url.clone()) {
+            break;
         }
-        if let Some(drafts) = parse_title_apply_from_raw_html(bundle)? {
-            return Ok(drafts);
+        let response = http
+            .fetch_bytes_with(ctx.run_id, source_id, &url, ctx.robots())
+            .await
+            .map_err(|err| AdapterError::fetch_failed(&url, err))?;
+        // An unchanged (304) page has no body to find the next link in, so the walk ends there.
+        if let Some(selector) = &target.pagination.next_selector {
+            next = next_page_url(&response.final_url, &response.body, selector)?;
         }
-        Ok(bundle_to_drafts(bundle))
+        pages.push(FetchedPage::from_response(response, "text/html"));
+    }
+    Ok(pages)
+}
+
+/// The absolute URL of the first `selector` match's `href` on an HTML page, if any.
+fn next_page_url(page_url: &str, body: &[u8], selector: &str) -> Result<Option<String>, AdapterError> {
+    let document = Html::parse_document(&decode_text(body, "text/html"));
+    let Some(href) = select_first_attr(&document, selector, "href")? else {
+        return Ok(None);
+    };
+    let base = url::Url::parse(page_url).with_context(|| format!("parsing page URL {page_url}"))?;
+    let next = base
+        .join(&href)
+        .with_context(|| format!("resolving next page link `{href}` on {page_url}"))?;
+    Ok(Some(next.to_string()))
+}
+
+#[async_trait]
+impl SourceAdapter for HtmlTitleLinkFixtureAdapter {
+    fn source_id(&self) -> &str {
+        self.source_id
+    }
+
+    fn crawlability(&self) -> Crawlability {
+        self.crawlability
+    }
+
+    fn extractor_version(&self) -> &str {
+        self.extractor_version
+    }
+
+    /// The title heading every parsed page is expected to have.
+    fn sentinel_selector(&self) -> Option<&str> {
+        Some("h1")
+    }
+
+    async fn fetch_listing(
+        &self,
+        http: &HttpFetcher,
+        ctx: &AdapterContext,
+        targets: &[ListingTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        let mut pages = Vec::new();
+        for target in targets {
+            pages.extend(fetch_listing_pages(http, ctx, self.source_id, target).await?);
+        }
+        Ok(pages)
+    }
+
+    fn parse_listing(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        if bundle.source_id != self.source_id {
+            return Err(AdapterError::source_mismatch(bundle, self.source_id));
+        }
+        if let Some(drafts) = parse_title_apply_from_raw_html(bundle)? {
+            return Ok(drafts);
+        }
+        Ok(bundle_to_drafts(bundle))
+    }
+
+    async fn fetch_detail(
+        &self,
+        http: &HttpFetcher,
+        ctx: &AdapterContext,
+        targets: &[DetailTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        fetch_html_pages(http, ctx, self.source_id, targets.iter().map(|t| t.url.as_str())).await
+    }
+
+    fn parse_detail(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        self.parse_listing(bundle)
+    }
+}
+
+#[async_trait]
+impl SourceAdapter for JsonTitleApplyFixtureAdapter {
+    fn source_id(&self) -> &str {
+        self.source_id
+    }
+
+    fn crawlability(&self) -> Crawlability {
+        self.crawlability
+    }
+
+    fn extractor_version(&self) -> &str {
+        self.extractor_version
+    }
+
+    async fn fetch_listing(
+        &self,
+        _http: &HttpFetcher,
+        _ctx: &AdapterContext,
+        _targets: &[ListingTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        Ok(Vec::new())
+    }
+
+    fn parse_listing(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        if bundle.source_id != self.source_id {
+            return Err(AdapterError::source_mismatch(bundle, self.source_id));
+        }
+        if let Some(drafts) = parse_title_apply_from_raw_json(bundle)? {
+            return Ok(drafts);
+        }
+        Ok(bundle_to_drafts(bundle))
     }
 
     async fn fetch_detail(
@@ -623,16 +1606,863 @@ impl SourceAdapter for HtmlTitleLinkFixtureAdapter {
     }
 }
 
+pub fn appen_crowdgen_adapter() -> impl SourceAdapter {
+    HtmlTitleLinkFixtureAdapter {
+        source_id: "appen-crowdgen",
+        crawlability: Crawlability::PublicHtml,
+        extractor_version: "appen-v1",
+    }
+}
+
+pub fn clickworker_adapter() -> impl SourceAdapter {
+    HtmlTitleLinkFixtureAdapter {
+        source_id: "clickworker",
+        crawlability: Crawlability::PublicHtml,
+        extractor_version: "clickworker-v1",
+    }
+}
+
+pub fn oneforma_jobs_adapter() -> impl SourceAdapter {
+    HtmlTitleLinkFixtureAdapter {
+        source_id: "oneforma-jobs",
+        crawlability: Crawlability::PublicHtml,
+        extractor_version: "oneforma-v1",
+    }
+}
+
+pub fn telus_ai_community_adapter() -> impl SourceAdapter {
+    HtmlTitleLinkFixtureAdapter {
+        source_id: "telus-ai-community",
+        crawlability: Crawlability::PublicHtml,
+        extractor_version: "telus-v1",
+    }
+}
+
+pub fn prolific_manual_adapter() -> impl SourceAdapter {
+    JsonTitleApplyFixtureAdapter {
+        source_id: "prolific",
+        crawlability: Crawlability::ManualOnly,
+        extractor_version: "prolific-manual-v1",
+    }
+}
+
+/// Generic fixture adapter that reads title, apply link and the common job-page selectors from
+/// raw HTML; what scaffolded adapters register until they need source-specific parsing.
+pub fn html_title_link_adapter(source_id: &'static str, extractor_version: &'static str) -> impl SourceAdapter {
+    HtmlTitleLinkFixtureAdapter {
+        source_id,
+        crawlability: Crawlability::PublicHtml,
+        extractor_version,
+    }
+}
+
+/// A compiled-in adapter, submitted with `inventory::submit!` next to its implementation (see
+/// `src/generated/` for scaffolded ones) and collected into the [`AdapterRegistry`].
+pub struct AdapterRegistration {
+    pub source_id: &'static str,
+    pub build: fn() -> Box<dyn SourceAdapter>,
+}
+
+inventory::collect!(AdapterRegistration);
+
+inventory::submit! { AdapterRegistration { source_id: "appen-crowdgen", build: || Box::new(appen_crowdgen_adapter()) } }
+inventory::submit! { AdapterRegistration { source_id: "clickworker", build: || Box::new(clickworker_adapter()) } }
+inventory::submit! { AdapterRegistration { source_id: "oneforma-jobs", build: || Box::new(oneforma_jobs_adapter()) } }
+inventory::submit! { AdapterRegistration { source_id: "telus-ai-community", build: || Box::new(telus_ai_community_adapter()) } }
+inventory::submit! { AdapterRegistration { source_id: "prolific", build: || Box::new(prolific_manual_adapter()) } }
+
+type AdapterConstructor = Arc<dyn Fn() -> Box<dyn SourceAdapter> + Send + Sync>;
+
+/// Adapter constructors by source id.
+#[derive(Clone, Default)]
+pub struct AdapterRegistry {
+    constructors: BTreeMap<String, AdapterConstructor>,
+}
+
+impl AdapterRegistry {
+    /// Every [`AdapterRegistration`] linked into the binary.
+    pub fn compiled_in() -> Self {
+        let mut registry = Self::default();
+        for registration in inventory::iter::<AdapterRegistration> {
+            registry.register(registration.source_id, registration.build);
+        }
+        registry
+    }
+
+    /// Add (or replace) the adapter for `source_id`.
+    pub fn register(
+        &mut self,
+        source_id: impl Into<String>,
+        build: impl Fn() -> Box<dyn SourceAdapter> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.constructors.insert(source_id.into(), Arc::new(build));
+        self
+    }
+
+    pub fn build(&self, source_id: &str) -> Option<Box<dyn SourceAdapter>> {
+        self.constructors.get(source_id).map(|build| build())
+    }
+
+    pub fn source_ids(&self) -> Vec<&str> {
+        self.constructors.keys().map(String::as_str).collect()
+    }
+
+    /// The [`ExtractorManifest`] of every registered adapter, by source id.
+    pub fn manifest(&self) -> Vec<ExtractorManifest> {
+        self.constructors.values().map(|build| build().manifest()).collect()
+    }
+}
+
+/// Process-wide registry behind [`adapter_for_source`]: the compiled-in adapters plus anything
+/// added through [`register_adapter`].
+static ADAPTERS: LazyLock<RwLock<AdapterRegistry>> = LazyLock::new(|| RwLock::new(AdapterRegistry::compiled_in()));
+
+/// Register (or replace) an adapter for the whole process, e.g. from a binary embedding the sync
+/// pipeline, so sources.yaml entries for it resolve without a rebuild of this crate.
+pub fn register_adapter(
+    source_id: impl Into<String>,
+    build: impl Fn() -> Box<dyn SourceAdapter> + Send + Sync + 'static,
+) {
+    ADAPTERS.write().unwrap_or_else(PoisonError::into_inner).register(source_id, build);
+}
+
+/// Extractor manifest of every registered adapter.
+pub fn extractor_manifest() -> Vec<ExtractorManifest> {
+    ADAPTERS.read().unwrap_or_else(PoisonError::into_inner).manifest()
+}
+
+/// Source ids with a registered adapter, sorted.
+pub fn registered_source_ids() -> Vec<String> {
+    let registry = ADAPTERS.read().unwrap_or_else(PoisonError::into_inner);
+    registry.source_ids().into_iter().map(str::to_string).collect()
+}
+
+pub fn adapter_for_source(source_id: &str) -> Option<Box<dyn SourceAdapter>> {
+    ADAPTERS.read().unwrap_or_else(PoisonError::into_inner).build(source_id)
+}
+
+/// File under `fixtures/<source_id>/` that turns a source into a [`ConfigurableHtmlAdapter`].
+pub const SELECTORS_FILE: &str = "selectors.yaml";
+
+/// CSS selectors of a [`ConfigurableHtmlAdapter`], read from `fixtures/<source_id>/selectors.yaml`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HtmlSelectors {
+    /// Each match is one listing; unset treats the whole page as a single listing.
+    #[serde(default)]
+    pub item: Option<String>,
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Element whose text holds the pay (model, range and currency are parsed from it).
+    #[serde(default)]
+    pub pay: Option<String>,
+    /// Link whose `href` (resolved against the page URL) is the apply URL.
+    #[serde(default)]
+    pub apply_url: Option<String>,
+    /// Bump when changing selectors changes parsed output; defaults to `<source_id>-selectors-v1`.
+    #[serde(default)]
+    pub extractor_version: Option<String>,
+}
+
+/// HTML adapter driven entirely by [`HtmlSelectors`], so sources that differ only by markup can be
+/// onboarded without Rust. Each field's evidence records `<item> <field>` as its selector.
+#[derive(Debug, Clone)]
+pub struct ConfigurableHtmlAdapter {
+    source_id: String,
+    extractor_version: String,
+    selectors: HtmlSelectors,
+}
+
+impl ConfigurableHtmlAdapter {
+    pub fn new(source_id: impl Into<String>, selectors: HtmlSelectors) -> Result<Self> {
+        let fields = [Some(&selectors.title), selectors.item.as_ref(), selectors.description.as_ref()]
+            .into_iter()
+            .chain([selectors.pay.as_ref(), selectors.apply_url.as_ref()])
+            .flatten();
+        for selector in fields {
+            Selector::parse(selector).map_err(|err| anyhow::anyhow!("invalid selector `{selector}`: {err}"))?;
+        }
+        let source_id = source_id.into();
+        let extractor_version = selectors
+            .extractor_version
+            .clone()
+            .unwrap_or_else(|| format!("{source_id}-selectors-v1"));
+        Ok(Self { source_id, extractor_version, selectors })
+    }
+
+    pub fn selectors_path(workspace_root: &Path, source_id: &str) -> PathBuf {
+        workspace_root.join("fixtures").join(source_id).join(SELECTORS_FILE)
+    }
+
+    /// The adapter described by the source's `selectors.yaml`, or `None` when it has none.
+    pub fn load(workspace_root: &Path, source_id: &str) -> Result<Option<Self>> {
+        let path = Self::selectors_path(workspace_root, source_id);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let text = fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        let selectors = serde_yaml::from_str(&text).with_context(|| format!("parsing {}", path.display()))?;
+        Self::new(source_id, selectors)
+            .map(Some)
+            .with_context(|| format!("validating {}", path.display()))
+    }
+
+    fn empty_draft(&self, bundle: &FixtureBundle) -> OpportunityDraft {
+        OpportunityDraft {
+            source_id: self.source_id.clone(),
+            listing_url: Some(bundle.captured_from_url.clone()),
+            detail_url: None,
+            external_id: None,
+            language: None,
+            fetched_at: bundle.fetched_at,
+            extractor_version: bundle.extractor_version.clone(),
+            title: Field::empty(),
+            description: Field::empty(),
+            pay_model: Field::empty(),
+            pay_unit: Field::empty(),
+            pay_rate_min: Field::empty(),
+            pay_rate_max: Field::empty(),
+            currency: Field::empty(),
+            min_hours_per_week: Field::empty(),
+            verification_requirements: Field::empty(),
+            geo_constraints: Field::empty(),
+            one_off_vs_ongoing: Field::empty(),
+            payment_methods: Field::empty(),
+            apply_url: Field::empty(),
+            requirements: Field::empty(),
+        }
+    }
+
+    fn item_draft(&self, bundle: &FixtureBundle, item: ElementRef<'_>) -> Result<OpportunityDraft, AdapterError> {
+        let scoped = |selector: &str| match &self.selectors.item {
+            Some(item) => format!("{item} {selector}"),
+            None => selector.to_string(),
+        };
+        let first = |selector: &str| -> Result<Option<ElementRef<'_>>, AdapterError> {
+            let sel = Selector::parse(selector).map_err(|e| AdapterError::ParseFailed(e.to_string()))?;
+            Ok(item.select(&sel).next())
+        };
+        let text_of = |el: ElementRef<'_>| text_or_none(el.text().collect::<String>());
+
+        let mut draft = self.empty_draft(bundle);
+        if let Some(title) = first(&self.selectors.title)?.and_then(text_of) {
+            set_from_raw(&mut draft.title, Some(title.clone()), bundle, &scoped(&self.selectors.title), &title);
+        }
+        if let Some(selector) = &self.selectors.description {
+            if let Some(desc) = first(selector)?.and_then(text_of) {
+                set_from_raw(&mut draft.description, Some(desc.clone()), bundle, &scoped(selector), &desc);
+            }
+        }
+        if let Some(selector) = &self.selectors.pay {
+            if let Some(pay) = first(selector)?.and_then(text_of) {
+                let parsed = parse_pay_fields(&pay);
+                let selector = scoped(selector);
+                set_from_raw(&mut draft.pay_model, parsed.model, bundle, &selector, &pay);
+                set_from_raw(&mut draft.pay_unit, parsed.unit, bundle, &selector, &pay);
+                set_from_raw(&mut draft.pay_rate_min, parsed.rate_min, bundle, &selector, &pay);
+                set_from_raw(&mut draft.pay_rate_max, parsed.rate_max, bundle, &selector, &pay);
+                set_from_raw(&mut draft.currency, parsed.currency, bundle, &selector, &pay);
+            }
+        }
+        if let Some(selector) = &self.selectors.apply_url {
+            let href = first(selector)?.and_then(|el| el.value().attr("href")).and_then(|h| text_or_none(h.to_string()));
+            if let Some(href) = href {
+                let url = resolve_href(&bundle.captured_from_url, &href);
+                set_from_raw(&mut draft.apply_url, Some(url), bundle, &scoped(selector), &href);
+            }
+        }
+        Ok(draft)
+    }
+}
+
 #[async_trait]
-impl SourceAdapter for JsonTitleApplyFixtureAdapter {
-    fn source_id(&self) -> &'static str {
-        self.source_id
+impl SourceAdapter for ConfigurableHtmlAdapter {
+    fn source_id(&self) -> &str {
+        &self.source_id
+    }
+
+    fn crawlability(&self) -> Crawlability {
+        Crawlability::PublicHtml
+    }
+
+    fn extractor_version(&self) -> &str {
+        &self.extractor_version
+    }
+
+    /// The `item` selector, or `title` for whole-page listings.
+    fn sentinel_selector(&self) -> Option<&str> {
+        Some(self.selectors.item.as_deref().unwrap_or(&self.selectors.title))
+    }
+
+    async fn fetch_listing(
+        &self,
+        http: &HttpFetcher,
+        ctx: &AdapterContext,
+        targets: &[ListingTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        let mut pages = Vec::new();
+        for target in targets {
+            pages.extend(fetch_listing_pages(http, ctx, &self.source_id, target).await?);
+        }
+        Ok(pages)
+    }
+
+    /// One draft per `item` match that has a title. A whole-page listing, or a page where no item
+    /// matched, falls back to OpenGraph/meta tags; a bundle without raw HTML falls back to its
+    /// parsed records.
+    fn parse_listing(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        if bundle.source_id != self.source_id {
+            return Err(AdapterError::source_mismatch(bundle, &self.source_id));
+        }
+        let Some(html) = bundle.raw_artifact.inline_text.as_deref() else {
+            return Ok(bundle_to_drafts(bundle));
+        };
+        let document = Html::parse_document(html);
+        let item_selector = self.selectors.item.as_deref().unwrap_or(":root");
+        let item_sel = Selector::parse(item_selector).map_err(|e| AdapterError::ParseFailed(e.to_string()))?;
+        let mut drafts = document
+            .select(&item_sel)
+            .map(|item| self.item_draft(bundle, item))
+            .collect::<Result<Vec<_>, _>>()?;
+        if self.selectors.item.is_some() {
+            drafts.retain(|draft| draft.title.value.is_some());
+        }
+        if self.selectors.item.is_none() || drafts.is_empty() {
+            let mut draft = drafts.pop().unwrap_or_else(|| self.empty_draft(bundle));
+            apply_meta_fallbacks(&document, bundle, &mut draft)?;
+            drafts.push(draft);
+        }
+        drafts.retain(|draft| draft.title.value.is_some());
+        Ok(drafts)
+    }
+
+    async fn fetch_detail(
+        &self,
+        http: &HttpFetcher,
+        ctx: &AdapterContext,
+        targets: &[DetailTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        fetch_html_pages(http, ctx, &self.source_id, targets.iter().map(|t| t.url.as_str())).await
+    }
+
+    fn parse_detail(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        self.parse_listing(bundle)
+    }
+}
+
+/// How an [`ApiAdapter`] authenticates. The credential itself never lives in `sources.yaml`: it is
+/// read from the environment variable named here when the source is fetched.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ApiAuth {
+    /// Header that carries the credential as-is (e.g. `X-Api-Key`); unset sends
+    /// `Authorization: Bearer <credential>`.
+    pub header: Option<String>,
+    /// Environment variable holding the credential; defaults to `RHOF_<SOURCE_ID>_API_TOKEN`.
+    pub secret_env: Option<String>,
+}
+
+impl ApiAuth {
+    pub fn secret_env_for(&self, source_id: &str) -> String {
+        self.secret_env
+            .clone()
+            .unwrap_or_else(|| source_env_name(source_id, "API_TOKEN"))
+    }
+}
+
+/// `RHOF_<SOURCE_ID>_<suffix>`, with the source id upper-cased and other characters as `_`.
+fn source_env_name(source_id: &str, suffix: &str) -> String {
+    let id = source_id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect::<String>();
+    format!("RHOF_{id}_{suffix}")
+}
+
+/// JSON pointers (RFC 6901, relative to one result) of the draft fields an [`ApiAdapter`] maps.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApiFieldMap {
+    pub title: String,
+    #[serde(default)]
+    pub external_id: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub pay_model: Option<String>,
+    /// Free text such as `per hour` or `/task`, normalized like HTML pay text.
+    #[serde(default)]
+    pub pay_unit: Option<String>,
+    #[serde(default)]
+    pub pay_rate_min: Option<String>,
+    #[serde(default)]
+    pub pay_rate_max: Option<String>,
+    #[serde(default)]
+    pub currency: Option<String>,
+    #[serde(default)]
+    pub apply_url: Option<String>,
+    #[serde(default)]
+    pub detail_url: Option<String>,
+}
+
+/// The `api:` block of an API source in `sources.yaml`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApiSourceConfig {
+    #[serde(default)]
+    pub auth: ApiAuth,
+    /// JSON pointer to the array of results in each response; empty means the response is the array.
+    #[serde(default)]
+    pub items_pointer: String,
+    /// JSON pointer to the next page's URL (cursor pagination). Listing URLs containing `{page}`
+    /// are numbered instead, until a page has no results.
+    #[serde(default)]
+    pub next_pointer: Option<String>,
+    pub fields: ApiFieldMap,
+}
+
+impl ApiSourceConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        let fields = &self.fields;
+        let optional = [
+            &self.next_pointer,
+            &fields.external_id,
+            &fields.description,
+            &fields.pay_model,
+            &fields.pay_unit,
+            &fields.pay_rate_min,
+            &fields.pay_rate_max,
+            &fields.currency,
+            &fields.apply_url,
+            &fields.detail_url,
+        ];
+        let pointers = [&self.items_pointer, &fields.title]
+            .into_iter()
+            .chain(optional.into_iter().flatten());
+        for pointer in pointers {
+            if !pointer.is_empty() && !pointer.starts_with('/') {
+                return Err(format!("api pointer `{pointer}` must be empty or start with `/`"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The array at `items_pointer` of a JSON response; empty when it is missing or not an array.
+fn json_items<'a>(body: &'a JsonValue, items_pointer: &str) -> &'a [JsonValue] {
+    body.pointer(items_pointer)
+        .and_then(JsonValue::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// Pages through JSON listing targets. `{page}` URLs stop at the first page without items (or a
+/// 404 after page 1); other URLs follow `next_pointer` until it is missing or repeats. Either way
+/// at most `max_pages` pages are fetched per target.
+struct JsonPager<'a> {
+    source_id: &'a str,
+    robots: RobotsPolicy,
+    headers: &'a [(String, String)],
+    items_pointer: &'a str,
+    next_pointer: Option<&'a str>,
+}
+
+impl JsonPager<'_> {
+    async fn fetch_page(&self, http: &HttpFetcher, ctx: &AdapterContext, url: &str) -> Result<(FetchedPage, JsonValue), AdapterError> {
+        let response = http
+            .fetch_bytes_with_headers(ctx.run_id, self.source_id, url, self.robots, self.headers)
+            .await
+            .map_err(|err| AdapterError::fetch_failed(url, err))?;
+        let page = FetchedPage::from_response(response, "application/json");
+        if page.not_modified {
+            return Ok((page, JsonValue::Null));
+        }
+        let body = serde_json::from_slice(&page.body)
+            .map_err(|err| AdapterError::ParseFailed(format!("parsing JSON from {url}: {err}")))?;
+        Ok((page, body))
+    }
+
+    async fn fetch_listing(
+        &self,
+        http: &HttpFetcher,
+        ctx: &AdapterContext,
+        targets: &[ListingTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        let mut pages = Vec::new();
+        for target in targets {
+            let max_pages = target.pagination.max_pages.max(1);
+            if target.url.contains(PAGE_PLACEHOLDER) {
+                for page_no in 1..=max_pages {
+                    let url = target.url.replace(PAGE_PLACEHOLDER, &page_no.to_string());
+                    let (page, body) = match self.fetch_page(http, ctx, &url).await {
+                        Ok(fetched) => fetched,
+                        Err(err)
+                            if page_no > 1 && matches!(err.fetch_error(), Some(FetchError::HttpStatus { status: 404, .. })) =>
+                        {
+                            break
+                        }
+                        Err(err) => return Err(err),
+                    };
+                    if !page.not_modified && json_items(&body, self.items_pointer).is_empty() {
+                        break;
+                    }
+                    pages.push(page);
+                }
+                continue;
+            }
+
+            let mut visited = HashSet::new();
+            let mut next = Some(target.url.clone());
+            let mut fetched = 0;
+            while let Some(url) = next.take() {
+                if fetched >= max_pages || !visited.insert(url.clone()) {
+                    break;
+                }
+                let (page, body) = self.fetch_page(http, ctx, &url).await?;
+                if let Some(pointer) = self.next_pointer {
+                    next = body
+                        .pointer(pointer)
+                        .and_then(JsonValue::as_str)
+                        .and_then(|href| text_or_none(href.to_string()))
+                        .map(|href| resolve_href(&page.url, &href));
+                }
+                pages.push(page);
+                fetched += 1;
+            }
+        }
+        Ok(pages)
+    }
+}
+
+/// Adapter for sources with an authenticated JSON API: pages through each listing URL with the
+/// source's credential and maps every result to a draft through [`ApiFieldMap`], with evidence
+/// pointing at the result's JSON pointer. API calls skip robots.txt, which governs crawlers rather
+/// than keyed API access.
+#[derive(Debug, Clone)]
+pub struct ApiAdapter {
+    source_id: String,
+    crawlability: Crawlability,
+    extractor_version: String,
+    config: ApiSourceConfig,
+}
+
+impl ApiAdapter {
+    pub fn new(source_id: impl Into<String>, crawlability: Crawlability, config: ApiSourceConfig) -> Result<Self> {
+        config.validate().map_err(anyhow::Error::msg)?;
+        let source_id = source_id.into();
+        let extractor_version = format!("{source_id}-api-v1");
+        Ok(Self { source_id, crawlability, extractor_version, config })
+    }
+
+    fn auth_headers(&self) -> Result<Vec<(String, String)>, AdapterError> {
+        let env = self.config.auth.secret_env_for(&self.source_id);
+        let secret = std::env::var(&env)
+            .ok()
+            .and_then(text_or_none)
+            .ok_or_else(|| AdapterError::Message(format!("{env} is not set; {} needs an API credential", self.source_id)))?;
+        Ok(vec![match &self.config.auth.header {
+            Some(header) => (header.clone(), secret),
+            None => ("Authorization".to_string(), format!("Bearer {secret}")),
+        }])
+    }
+
+    fn items<'a>(&self, body: &'a JsonValue) -> &'a [JsonValue] {
+        json_items(body, &self.config.items_pointer)
+    }
+
+    fn item_draft(&self, bundle: &FixtureBundle, index: usize, item: &JsonValue) -> OpportunityDraft {
+        let fields = &self.config.fields;
+        let at = |pointer: &Option<String>| {
+            let pointer = pointer.as_deref()?;
+            let value = item.pointer(pointer).filter(|v| !v.is_null())?;
+            let path = format!("{}/{index}{pointer}", self.config.items_pointer);
+            Some((value, path, value.to_string()))
+        };
+        let text = |value: &JsonValue| match value {
+            JsonValue::String(s) => text_or_none(s.clone()),
+            other => Some(other.to_string()),
+        };
+        let number = |value: &JsonValue| value.as_f64().or_else(|| value.as_str()?.trim().parse().ok());
+
+        let mut draft = OpportunityDraft {
+            source_id: self.source_id.clone(),
+            listing_url: Some(bundle.captured_from_url.clone()),
+            detail_url: at(&fields.detail_url).and_then(|(value, _, _)| text(value)),
+            external_id: at(&fields.external_id).and_then(|(value, _, _)| text(value)),
+            language: None,
+            fetched_at: bundle.fetched_at,
+            extractor_version: bundle.extractor_version.clone(),
+            title: Field::empty(),
+            description: Field::empty(),
+            pay_model: Field::empty(),
+            pay_unit: Field::empty(),
+            pay_rate_min: Field::empty(),
+            pay_rate_max: Field::empty(),
+            currency: Field::empty(),
+            min_hours_per_week: Field::empty(),
+            verification_requirements: Field::empty(),
+            geo_constraints: Field::empty(),
+            one_off_vs_ongoing: Field::empty(),
+            payment_methods: Field::empty(),
+            apply_url: Field::empty(),
+            requirements: Field::empty(),
+        };
+        let text_fields = [
+            (&mut draft.title, Some(fields.title.clone())),
+            (&mut draft.description, fields.description.clone()),
+            (&mut draft.pay_model, fields.pay_model.clone()),
+            (&mut draft.currency, fields.currency.clone()),
+            (&mut draft.apply_url, fields.apply_url.clone()),
+        ];
+        for (field, pointer) in text_fields {
+            if let Some((value, path, snippet)) = at(&pointer) {
+                set_from_raw(field, text(value), bundle, &path, &snippet);
+            }
+        }
+        if let Some((value, path, snippet)) = at(&fields.pay_unit) {
+            let unit = text(value).and_then(|unit| detect_pay_unit(&unit));
+            set_from_raw(&mut draft.pay_unit, unit.map(str::to_string), bundle, &path, &snippet);
+        }
+        for (field, pointer) in [(&mut draft.pay_rate_min, &fields.pay_rate_min), (&mut draft.pay_rate_max, &fields.pay_rate_max)] {
+            if let Some((value, path, snippet)) = at(pointer) {
+                set_from_raw(field, number(value), bundle, &path, &snippet);
+            }
+        }
+        draft
+    }
+}
+
+#[async_trait]
+impl SourceAdapter for ApiAdapter {
+    fn source_id(&self) -> &str {
+        &self.source_id
     }
 
     fn crawlability(&self) -> Crawlability {
         self.crawlability
     }
 
+    fn extractor_version(&self) -> &str {
+        &self.extractor_version
+    }
+
+    /// Fetch the first listing page with the source's credential and check that `items_pointer`
+    /// still resolves to an array.
+    async fn health_check(&self, http: &HttpFetcher, ctx: &AdapterContext, targets: &[ListingTarget]) -> AdapterHealth {
+        let Some(url) = health_check_url(targets) else {
+            return AdapterHealth::skipped(&self.source_id, "source has no listing URL");
+        };
+        let headers = match self.auth_headers() {
+            Ok(headers) => headers,
+            Err(err) => {
+                return AdapterHealth::probe(&self.source_id, &url, &self.config.items_pointer)
+                    .fail(HealthStatus::Unreachable, err.to_string())
+            }
+        };
+        probe_json_listing(http, ctx, &self.source_id, &url, &headers, &self.config.items_pointer).await
+    }
+
+    /// One page per response, paged as described on [`JsonPager`].
+    async fn fetch_listing(
+        &self,
+        http: &HttpFetcher,
+        ctx: &AdapterContext,
+        targets: &[ListingTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        let headers = self.auth_headers()?;
+        let pager = JsonPager {
+            source_id: &self.source_id,
+            robots: RobotsPolicy::Ignore,
+            headers: &headers,
+            items_pointer: &self.config.items_pointer,
+            next_pointer: self.config.next_pointer.as_deref(),
+        };
+        pager.fetch_listing(http, ctx, targets).await
+    }
+
+    /// One draft per result with a title; a bundle without a raw response (e.g. a manual
+    /// fixture) falls back to its parsed records.
+    fn parse_listing(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        if bundle.source_id != self.source_id {
+            return Err(AdapterError::source_mismatch(bundle, &self.source_id));
+        }
+        let Some(text) = bundle.raw_artifact.inline_text.as_deref() else {
+            return Ok(bundle_to_drafts(bundle));
+        };
+        let body: JsonValue = serde_json::from_str(text)
+            .map_err(|err| AdapterError::ParseFailed(format!("parsing API response: {err}")))?;
+        Ok(self
+            .items(&body)
+            .iter()
+            .enumerate()
+            .map(|(index, item)| self.item_draft(bundle, index, item))
+            .filter(|draft| draft.title.value.is_some())
+            .collect())
+    }
+
+    async fn fetch_detail(
+        &self,
+        _http: &HttpFetcher,
+        _ctx: &AdapterContext,
+        _targets: &[DetailTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        Ok(Vec::new())
+    }
+
+    fn parse_detail(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        self.parse_listing(bundle)
+    }
+}
+
+/// Spreadsheet export read from `manual/<source_id>/` by [`ManualCsvAdapter`].
+pub const MANUAL_CSV_FILE: &str = "listings.csv";
+
+/// Columns [`ManualCsvAdapter`] reads (matched case-insensitively); only `title` is required.
+pub const MANUAL_CSV_COLUMNS: [&str; 7] = ["title", "description", "pay", "currency", "apply_url", "geo", "id"];
+
+/// Adapter for sources whose only feed is a person copying listings into a spreadsheet: one draft
+/// per row of `manual/<source_id>/listings.csv`, with evidence pointing at the row and column each
+/// value came from (`row=<line>;column=<name>`).
+#[derive(Debug, Clone)]
+pub struct ManualCsvAdapter {
+    source_id: String,
+    extractor_version: String,
+}
+
+impl ManualCsvAdapter {
+    pub fn new(source_id: impl Into<String>) -> Self {
+        let source_id = source_id.into();
+        let extractor_version = format!("{source_id}-csv-v1");
+        Self { source_id, extractor_version }
+    }
+
+    pub fn csv_path(workspace_root: &Path, source_id: &str) -> PathBuf {
+        workspace_root.join("manual").join(source_id).join(MANUAL_CSV_FILE)
+    }
+
+    /// The adapter for a source with a `listings.csv`, or `None` when it has none.
+    pub fn load(workspace_root: &Path, source_id: &str) -> Option<Self> {
+        Self::csv_path(workspace_root, source_id).is_file().then(|| Self::new(source_id))
+    }
+
+    fn row_draft(&self, bundle: &FixtureBundle, line: u64, columns: &[(String, String)]) -> OpportunityDraft {
+        let mut draft = OpportunityDraft {
+            source_id: self.source_id.clone(),
+            listing_url: Some(bundle.captured_from_url.clone()),
+            detail_url: None,
+            external_id: None,
+            language: None,
+            fetched_at: bundle.fetched_at,
+            extractor_version: bundle.extractor_version.clone(),
+            title: Field::empty(),
+            description: Field::empty(),
+            pay_model: Field::empty(),
+            pay_unit: Field::empty(),
+            pay_rate_min: Field::empty(),
+            pay_rate_max: Field::empty(),
+            currency: Field::empty(),
+            min_hours_per_week: Field::empty(),
+            verification_requirements: Field::empty(),
+            geo_constraints: Field::empty(),
+            one_off_vs_ongoing: Field::empty(),
+            payment_methods: Field::empty(),
+            apply_url: Field::empty(),
+            requirements: Field::empty(),
+        };
+        for (column, cell) in columns {
+            let Some(value) = text_or_none(cell.clone()) else {
+                continue;
+            };
+            let pointer = format!("row={line};column={column}");
+            match column.as_str() {
+                "title" => {
+                    set_from_raw(&mut draft.title, Some(value), bundle, &pointer, cell);
+                }
+                "description" => {
+                    set_from_raw(&mut draft.description, Some(value), bundle, &pointer, cell);
+                }
+                "pay" => {
+                    let parsed = parse_pay_fields(&value);
+                    set_from_raw(&mut draft.pay_model, parsed.model, bundle, &pointer, cell);
+                    set_from_raw(&mut draft.pay_unit, parsed.unit, bundle, &pointer, cell);
+                    set_from_raw(&mut draft.pay_rate_min, parsed.rate_min, bundle, &pointer, cell);
+                    set_from_raw(&mut draft.pay_rate_max, parsed.rate_max, bundle, &pointer, cell);
+                    if draft.currency.value.is_none() {
+                        set_from_raw(&mut draft.currency, parsed.currency, bundle, &pointer, cell);
+                    }
+                }
+                "currency" => {
+                    draft.currency = Field::empty();
+                    set_from_raw(&mut draft.currency, Some(value.to_ascii_uppercase()), bundle, &pointer, cell);
+                }
+                "apply_url" => {
+                    set_from_raw(&mut draft.apply_url, Some(value), bundle, &pointer, cell);
+                }
+                "geo" => {
+                    set_from_raw(&mut draft.geo_constraints, Some(value), bundle, &pointer, cell);
+                }
+                "id" => draft.external_id = Some(value),
+                _ => {}
+            }
+        }
+        draft
+    }
+}
+
+/// Wrap a `listings.csv` in a bundle so it flows through the pipeline like any manual fixture:
+/// the CSV is the raw artifact (content-addressed fixture id, file mtime as the fetch time) and
+/// the parsed records are left empty for [`ManualCsvAdapter`] to fill.
+pub fn load_manual_csv_bundle(path: impl AsRef<Path>) -> Result<FixtureBundle> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let source_id = path
+        .parent()
+        .and_then(Path::file_name)
+        .and_then(|name| name.to_str())
+        .with_context(|| format!("{} is not under manual/<source_id>/", path.display()))?
+        .to_string();
+    let fetched_at = fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .map(DateTime::<Utc>::from)
+        .with_context(|| format!("reading modification time of {}", path.display()))?;
+    let sha256 = ArtifactStore::sha256_hex(text.as_bytes());
+    let file_name = path.file_name().and_then(|name| name.to_str()).unwrap_or(MANUAL_CSV_FILE);
+    Ok(FixtureBundle {
+        schema_version: FIXTURE_BUNDLE_SCHEMA_VERSION,
+        fixture_id: format!("csv-{}", &sha256[..12]),
+        captured_from_url: format!("manual://{source_id}/{file_name}"),
+        extractor_version: ManualCsvAdapter::new(source_id.as_str()).extractor_version,
+        source_id,
+        crawlability: Crawlability::ManualOnly,
+        fetched_at,
+        raw_artifact: FixtureRawArtifact {
+            content_type: "text/csv".to_string(),
+            path: Some(file_name.to_string()),
+            inline_text: Some(text),
+            sha256: Some(sha256),
+        },
+        artifacts: Vec::new(),
+        parsed_records: Vec::new(),
+        evidence_coverage_percent: 100.0,
+        notes: Some("bulk manual import".to_string()),
+    })
+}
+
+#[async_trait]
+impl SourceAdapter for ManualCsvAdapter {
+    fn source_id(&self) -> &str {
+        &self.source_id
+    }
+
+    fn crawlability(&self) -> Crawlability {
+        Crawlability::ManualOnly
+    }
+
+    fn extractor_version(&self) -> &str {
+        &self.extractor_version
+    }
+
     async fn fetch_listing(
         &self,
         _http: &HttpFetcher,
@@ -642,92 +2472,517 @@ impl SourceAdapter for JsonTitleApplyFixtureAdapter {
         Ok(Vec::new())
     }
 
-    fn parse_listing(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
-        if bundle.source_id != self.source_id {
-            return Err(AdapterError::Message(format!(
-                "bundle source_id={} does not match adapter source_id={}",
-                bundle.source_id, self.source_id
-            )));
+    /// One draft per CSV row with a title. Unknown columns are ignored; a file without a `title`
+    /// column is an error.
+    fn parse_listing(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        if bundle.source_id != self.source_id {
+            return Err(AdapterError::source_mismatch(bundle, &self.source_id));
+        }
+        let Some(text) = bundle.raw_artifact.inline_text.as_deref() else {
+            return Ok(bundle_to_drafts(bundle));
+        };
+        let mut reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(text.as_bytes());
+        let headers = reader
+            .headers()
+            .map_err(|err| AdapterError::ParseFailed(format!("reading CSV header: {err}")))?
+            .iter()
+            .map(str::to_ascii_lowercase)
+            .collect::<Vec<_>>();
+        if !headers.iter().any(|header| header == "title") {
+            return Err(AdapterError::SchemaMismatch(format!(
+                "CSV header must include a `title` column (known columns: {})",
+                MANUAL_CSV_COLUMNS.join(", ")
+            )));
+        }
+        let mut drafts = Vec::new();
+        for record in reader.records() {
+            let record = record.map_err(|err| AdapterError::ParseFailed(format!("reading CSV row: {err}")))?;
+            let line = record.position().map_or(0, csv::Position::line);
+            let columns = headers
+                .iter()
+                .cloned()
+                .zip(record.iter().map(str::to_string))
+                .collect::<Vec<_>>();
+            let draft = self.row_draft(bundle, line, &columns);
+            if draft.title.value.is_some() {
+                drafts.push(draft);
+            }
+        }
+        Ok(drafts)
+    }
+
+    async fn fetch_detail(
+        &self,
+        _http: &HttpFetcher,
+        _ctx: &AdapterContext,
+        _targets: &[DetailTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        Ok(Vec::new())
+    }
+
+    fn parse_detail(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        self.parse_listing(bundle)
+    }
+}
+
+/// The adapter for a source: an [`ApiAdapter`] when it has an `api:` block, else the built-in
+/// adapter for `source_id`, else the [`ConfigurableHtmlAdapter`] its `selectors.yaml` describes,
+/// else the [`GenericJsonAdapter`] its `json_mapping.yaml` describes, else the
+/// [`ManualEntryAdapter`] for its `manual/<source_id>/entries.json`, else the [`ManualCsvAdapter`]
+/// for its `manual/<source_id>/listings.csv`.
+pub fn resolve_adapter(
+    workspace_root: &Path,
+    source_id: &str,
+    crawlability: Crawlability,
+    api: Option<&ApiSourceConfig>,
+) -> Result<Option<Box<dyn SourceAdapter>>> {
+    if let Some(api) = api {
+        let adapter = ApiAdapter::new(source_id, crawlability, api.clone())?;
+        return Ok(Some(Box::new(adapter)));
+    }
+    if let Some(adapter) = adapter_for_source(source_id) {
+        return Ok(Some(adapter));
+    }
+    if let Some(adapter) = ConfigurableHtmlAdapter::load(workspace_root, source_id)? {
+        return Ok(Some(Box::new(adapter)));
+    }
+    if let Some(adapter) = GenericJsonAdapter::load(workspace_root, source_id, crawlability)? {
+        return Ok(Some(Box::new(adapter)));
+    }
+    if let Some(adapter) = ManualEntryAdapter::load(workspace_root, source_id) {
+        return Ok(Some(Box::new(adapter)));
+    }
+    Ok(ManualCsvAdapter::load(workspace_root, source_id).map(|adapter| Box::new(adapter) as Box<dyn SourceAdapter>))
+}
+
+/// One draft as recorded in a fixture's `snapshot.json`: the fields the golden tests compare.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoldenDraft {
+    pub title: Option<String>,
+    pub apply_url: Option<String>,
+    pub pay_model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pay_unit: Option<String>,
+    pub pay_rate_min: Option<f64>,
+    pub pay_rate_max: Option<f64>,
+    pub currency: Option<String>,
+    pub crawlability: Crawlability,
+}
+
+pub fn golden_drafts(drafts: &[OpportunityDraft], crawlability: Crawlability) -> Vec<GoldenDraft> {
+    drafts
+        .iter()
+        .map(|d| GoldenDraft {
+            title: d.title.value.clone(),
+            apply_url: d.apply_url.value.clone(),
+            pay_model: d.pay_model.value.clone(),
+            pay_unit: d.pay_unit.value.clone(),
+            pay_rate_min: d.pay_rate_min.value,
+            pay_rate_max: d.pay_rate_max.value,
+            currency: d.currency.value.clone(),
+            crawlability,
+        })
+        .collect()
+}
+
+/// Set (to anything but `0`) to make [`check_golden_snapshot`] rewrite out-of-date snapshots
+/// instead of failing, e.g. `UPDATE_SNAPSHOTS=1 cargo test -p rhof-adapters`.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "UPDATE_SNAPSHOTS";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotCheck {
+    Matched,
+    /// The snapshot differed (or was missing) and was rewritten from the parser output.
+    Updated,
+}
+
+/// Compare parser output against a golden `snapshot.json`. A mismatch is an error naming both
+/// sides, unless [`UPDATE_SNAPSHOTS_ENV`] is set, in which case the snapshot is regenerated.
+pub fn check_golden_snapshot(path: impl AsRef<Path>, actual: &[GoldenDraft]) -> Result<SnapshotCheck> {
+    let update = std::env::var(UPDATE_SNAPSHOTS_ENV).is_ok_and(|value| !value.is_empty() && value != "0");
+    reconcile_golden_snapshot(path.as_ref(), actual, update)
+}
+
+fn reconcile_golden_snapshot(path: &Path, actual: &[GoldenDraft], update: bool) -> Result<SnapshotCheck> {
+    let expected = match fs::read_to_string(path) {
+        Ok(text) => Some(serde_json::from_str::<Vec<GoldenDraft>>(&text).with_context(|| format!("parsing {}", path.display()))?),
+        Err(err) if update && err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err).with_context(|| format!("reading {}", path.display())),
+    };
+    if expected.as_deref() == Some(actual) {
+        return Ok(SnapshotCheck::Matched);
+    }
+    if update {
+        write_golden_snapshot(path, actual)?;
+        return Ok(SnapshotCheck::Updated);
+    }
+    anyhow::bail!(
+        "{} is out of date (rerun with {UPDATE_SNAPSHOTS_ENV}=1 to regenerate it)\nexpected: {}\nactual:   {}",
+        path.display(),
+        serde_json::to_string(&expected).unwrap_or_default(),
+        serde_json::to_string(actual).unwrap_or_default()
+    )
+}
+
+/// Write drafts in the compact one-line layout the committed snapshots use.
+pub fn write_golden_snapshot(path: impl AsRef<Path>, drafts: &[GoldenDraft]) -> Result<()> {
+    let path = path.as_ref();
+    let snapshot = serde_json::to_string(drafts).context("serializing snapshot")?;
+    fs::write(path, snapshot + "\n").with_context(|| format!("writing {}", path.display()))
+}
+
+fn draft_field_to_fixture<T: Clone>(field: &Field<T>) -> FixtureField<T> {
+    FixtureField {
+        value: field.value.clone(),
+        selector_or_pointer: field.evidence.as_ref().map(|e| e.selector_or_pointer.clone()).unwrap_or_default(),
+        snippet: field.evidence.as_ref().map(|e| e.snippet.clone()).unwrap_or_default(),
+        artifact_url: None,
+    }
+}
+
+fn draft_to_fixture_record(draft: &OpportunityDraft) -> FixtureParsedRecord {
+    FixtureParsedRecord {
+        title: draft_field_to_fixture(&draft.title),
+        description: draft_field_to_fixture(&draft.description),
+        pay_model: draft_field_to_fixture(&draft.pay_model),
+        pay_unit: draft_field_to_fixture(&draft.pay_unit),
+        pay_rate_min: draft_field_to_fixture(&draft.pay_rate_min),
+        pay_rate_max: draft_field_to_fixture(&draft.pay_rate_max),
+        currency: draft_field_to_fixture(&draft.currency),
+        min_hours_per_week: draft_field_to_fixture(&draft.min_hours_per_week),
+        verification_requirements: draft_field_to_fixture(&draft.verification_requirements),
+        geo_constraints: draft_field_to_fixture(&draft.geo_constraints),
+        one_off_vs_ongoing: draft_field_to_fixture(&draft.one_off_vs_ongoing),
+        payment_methods: draft_field_to_fixture(&draft.payment_methods),
+        apply_url: draft_field_to_fixture(&draft.apply_url),
+        requirements: draft_field_to_fixture(&draft.requirements),
+        listing_url: draft.listing_url.clone(),
+        detail_url: draft.detail_url.clone(),
+        external_id: draft.external_id.clone(),
+    }
+}
+
+/// Share (0-100, one decimal) of the populated fields among `(populated, has_evidence)` pairs
+/// that carry evidence; 100 when none are populated.
+fn coverage_of(fields: impl IntoIterator<Item = (bool, bool)>) -> f64 {
+    let (mut populated, mut evidenced) = (0usize, 0usize);
+    for (has_value, has_evidence) in fields {
+        populated += usize::from(has_value);
+        evidenced += usize::from(has_value && has_evidence);
+    }
+    if populated == 0 {
+        100.0
+    } else {
+        (evidenced as f64 * 1000.0 / populated as f64).round() / 10.0
+    }
+}
+
+/// How far (in percentage points) a bundle's declared `evidence_coverage_percent` may be from the
+/// computed value before it counts as wrong; declared values carry one decimal.
+pub const EVIDENCE_COVERAGE_TOLERANCE: f64 = 0.05;
+
+/// `(field, populated, has_evidence)` for the canonical fields that must cite evidence.
+pub fn canonical_evidence_checks(draft: &OpportunityDraft) -> [(&'static str, bool, bool); 5] {
+    [
+        ("title", draft.title.value.is_some(), draft.title.evidence.is_some()),
+        ("description", draft.description.value.is_some(), draft.description.evidence.is_some()),
+        ("pay_model", draft.pay_model.value.is_some(), draft.pay_model.evidence.is_some()),
+        ("currency", draft.currency.value.is_some(), draft.currency.evidence.is_some()),
+        ("apply_url", draft.apply_url.value.is_some(), draft.apply_url.evidence.is_some()),
+    ]
+}
+
+/// Evidence coverage actually achieved by parsed drafts: populated canonical fields with evidence
+/// over populated canonical fields, as a percentage with one decimal (100 when nothing is
+/// populated). This is what a bundle's `evidence_coverage_percent` should declare.
+pub fn evidence_coverage_percent(drafts: &[OpportunityDraft]) -> f64 {
+    coverage_of(
+        drafts
+            .iter()
+            .flat_map(canonical_evidence_checks)
+            .map(|(_, populated, has_evidence)| (populated, has_evidence)),
+    )
+}
+
+/// One problem found by [`validate_fixture_bundle`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FixtureBundleIssue {
+    pub field: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for FixtureBundleIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Check a fixture bundle against its raw artifact: required fields are present, a declared
+/// `sha256` matches the raw bytes, `evidence_coverage_percent` matches what the parsed records
+/// actually evidence, and every populated field's `selector_or_pointer` resolves in the raw
+/// artifact (a CSS selector for HTML, a JSON pointer or `$.a.b` path for JSON). Errors only
+/// when the bundle itself cannot be read.
+pub fn validate_fixture_bundle(path: impl AsRef<Path>) -> Result<Vec<FixtureBundleIssue>> {
+    let path = path.as_ref();
+    if path.extension().is_some_and(|ext| ext == "csv") {
+        return validate_manual_csv(path);
+    }
+    if path.file_name().is_some_and(|name| name == MANUAL_ENTRIES_FILE) {
+        return validate_manual_entries(path);
+    }
+    let bundle = read_bundle_file(path)?;
+    let mut issues = Vec::new();
+    let mut push = |field: String, message: String| issues.push(FixtureBundleIssue { field, message });
+
+    for (field, value) in [
+        ("fixture_id", &bundle.fixture_id),
+        ("source_id", &bundle.source_id),
+        ("captured_from_url", &bundle.captured_from_url),
+        ("extractor_version", &bundle.extractor_version),
+        ("raw_artifact.content_type", &bundle.raw_artifact.content_type),
+    ] {
+        if value.trim().is_empty() {
+            push(field.to_string(), "is required".to_string());
+        }
+    }
+    if bundle.parsed_records.is_empty() {
+        push("parsed_records".to_string(), "must contain at least one record".to_string());
+    }
+
+    let raw = read_fixture_raw_artifact(path, &bundle.raw_artifact, "raw_artifact", &mut push);
+    let mut artifact_raws = Vec::with_capacity(bundle.artifacts.len());
+    for (index, artifact) in bundle.artifacts.iter().enumerate() {
+        let field = format!("artifacts[{index}]");
+        if artifact.url.trim().is_empty() {
+            push(format!("{field}.url"), "is required".to_string());
+        } else if artifact.url == bundle.captured_from_url
+            || bundle.artifact_for_url(&artifact.url).is_some_and(|first| !std::ptr::eq(first, artifact))
+        {
+            push(format!("{field}.url"), format!("`{}` is already another artifact's URL", artifact.url));
         }
-        if let Some(drafts) = parse_title_apply_from_raw_json(bundle)? {
-            return Ok(drafts);
+        if artifact.raw.content_type.trim().is_empty() {
+            push(format!("{field}.content_type"), "is required".to_string());
         }
-        Ok(bundle_to_drafts(bundle))
+        artifact_raws.push(read_fixture_raw_artifact(path, &artifact.raw, &field, &mut push));
     }
 
-    async fn fetch_detail(
-        &self,
-        _http: &HttpFetcher,
-        _ctx: &AdapterContext,
-        _targets: &[DetailTarget],
-    ) -> Result<Vec<FetchedPage>, AdapterError> {
-        Ok(Vec::new())
+    let computed = coverage_of(bundle.parsed_records.iter().flat_map(|record| {
+        record.evidence_fields()[..5]
+            .iter()
+            .map(|(_, populated, selector, _)| (*populated, !selector.is_empty()))
+            .collect::<Vec<_>>()
+    }));
+    if (computed - bundle.evidence_coverage_percent).abs() > EVIDENCE_COVERAGE_TOLERANCE {
+        push(
+            "evidence_coverage_percent".to_string(),
+            format!(
+                "declared {} but the parsed records evidence {computed}",
+                bundle.evidence_coverage_percent
+            ),
+        );
     }
 
-    fn parse_detail(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
-        self.parse_listing(bundle)
+    let raw_doc = raw.and_then(|raw| RawDocument::parse(&raw, &bundle.raw_artifact, "raw_artifact", &mut push));
+    let artifact_docs = bundle
+        .artifacts
+        .iter()
+        .zip(artifact_raws)
+        .enumerate()
+        .map(|(index, (artifact, raw))| {
+            let doc = raw.and_then(|raw| RawDocument::parse(&raw, &artifact.raw, &format!("artifacts[{index}]"), &mut push));
+            (artifact.url.as_str(), doc)
+        })
+        .collect::<Vec<_>>();
+    for (index, record) in bundle.parsed_records.iter().enumerate() {
+        for (name, populated, selector, artifact_url) in record.evidence_fields() {
+            if !populated || selector.is_empty() {
+                continue;
+            }
+            let doc = match artifact_url {
+                None => raw_doc.as_ref(),
+                Some(url) => match artifact_docs.iter().find(|(artifact_url, _)| *artifact_url == url) {
+                    Some((_, doc)) => doc.as_ref(),
+                    None => {
+                        push(format!("parsed_records[{index}].{name}"), format!("artifact_url `{url}` names no artifact of the bundle"));
+                        continue;
+                    }
+                },
+            };
+            if let Some(Err(message)) = doc.map(|doc| doc.resolve(selector)) {
+                push(format!("parsed_records[{index}].{name}"), message);
+            }
+        }
     }
+    Ok(issues)
 }
 
-pub fn appen_crowdgen_adapter() -> impl SourceAdapter {
-    HtmlTitleLinkFixtureAdapter {
-        source_id: "appen-crowdgen",
-        crawlability: Crawlability::PublicHtml,
+/// The bytes of one of a bundle's raw artifacts (inline or at `path`, relative to the bundle),
+/// checked against its declared `sha256`; `field` names it in issues.
+fn read_fixture_raw_artifact(
+    bundle_path: &Path,
+    raw_artifact: &FixtureRawArtifact,
+    field: &str,
+    push: &mut impl FnMut(String, String),
+) -> Option<Vec<u8>> {
+    let raw = match (&raw_artifact.inline_text, &raw_artifact.path) {
+        (Some(inline_text), _) => inline_text.as_bytes().to_vec(),
+        (None, Some(rel_path)) => {
+            let raw_path = bundle_path.parent().unwrap_or_else(|| Path::new(".")).join(rel_path);
+            match fs::read(&raw_path) {
+                Ok(bytes) => bytes,
+                Err(err) => {
+                    push(format!("{field}.path"), format!("cannot read {}: {err}", raw_path.display()));
+                    return None;
+                }
+            }
+        }
+        (None, None) => {
+            push(field.to_string(), "needs a `path` or `inline_text`".to_string());
+            return None;
+        }
+    };
+    if let Some(declared) = &raw_artifact.sha256 {
+        let actual = ArtifactStore::sha256_hex(&raw);
+        if !declared.eq_ignore_ascii_case(&actual) {
+            push(format!("{field}.sha256"), format!("declared {declared} but the raw artifact hashes to {actual}"));
+        }
     }
+    Some(raw)
 }
 
-pub fn clickworker_adapter() -> impl SourceAdapter {
-    HtmlTitleLinkFixtureAdapter {
-        source_id: "clickworker",
-        crawlability: Crawlability::PublicHtml,
-    }
+/// A `listings.csv` has no declared records to check; it must parse with a `title` column and
+/// yield at least one draft.
+fn validate_manual_csv(path: &Path) -> Result<Vec<FixtureBundleIssue>> {
+    let bundle = load_manual_csv_bundle(path)?;
+    let issue = |field: &str, message: String| FixtureBundleIssue { field: field.to_string(), message };
+    Ok(match ManualCsvAdapter::new(bundle.source_id.as_str()).parse_listing(&bundle) {
+        Ok(drafts) if drafts.is_empty() => vec![issue("rows", "no row has a title".to_string())],
+        Ok(_) => Vec::new(),
+        Err(err) => vec![issue("csv", err.to_string())],
+    })
 }
 
-pub fn oneforma_jobs_adapter() -> impl SourceAdapter {
-    HtmlTitleLinkFixtureAdapter {
-        source_id: "oneforma-jobs",
-        crawlability: Crawlability::PublicHtml,
-    }
+enum RawDocument {
+    Html(Html),
+    Json(JsonValue),
 }
 
-pub fn telus_ai_community_adapter() -> impl SourceAdapter {
-    HtmlTitleLinkFixtureAdapter {
-        source_id: "telus-ai-community",
-        crawlability: Crawlability::PublicHtml,
+impl RawDocument {
+    fn parse(raw: &[u8], raw_artifact: &FixtureRawArtifact, field: &str, push: &mut impl FnMut(String, String)) -> Option<Self> {
+        let text = decode_text(raw, &raw_artifact.content_type);
+        if !raw_artifact.content_type.contains("json") {
+            return Some(Self::Html(Html::parse_document(&text)));
+        }
+        match serde_json::from_str::<JsonValue>(&text) {
+            Ok(json) => Some(Self::Json(json)),
+            Err(err) => {
+                push(field.to_string(), format!("content type is JSON but the body does not parse: {err}"));
+                None
+            }
+        }
     }
-}
 
-pub fn prolific_manual_adapter() -> impl SourceAdapter {
-    JsonTitleApplyFixtureAdapter {
-        source_id: "prolific",
-        crawlability: Crawlability::ManualOnly,
+    fn resolve(&self, selector_or_pointer: &str) -> std::result::Result<(), String> {
+        match self {
+            Self::Html(document) => {
+                let selector = Selector::parse(selector_or_pointer)
+                    .map_err(|err| format!("invalid CSS selector `{selector_or_pointer}`: {err}"))?;
+                if document.select(&selector).next().is_none() {
+                    return Err(format!("selector `{selector_or_pointer}` matches nothing in the raw artifact"));
+                }
+            }
+            Self::Json(json) => {
+                let pointer = json_path_to_pointer(selector_or_pointer)?;
+                if json.pointer(&pointer).is_none() {
+                    return Err(format!("pointer `{selector_or_pointer}` resolves to nothing in the raw artifact"));
+                }
+            }
+        }
+        Ok(())
     }
 }
 
-pub fn adapter_for_source(source_id: &str) -> Option<Box<dyn SourceAdapter>> {
-    match source_id {
-        "appen-crowdgen" => Some(Box::new(HtmlTitleLinkFixtureAdapter {
-            source_id: "appen-crowdgen",
-            crawlability: Crawlability::PublicHtml,
-        })),
-        "clickworker" => Some(Box::new(HtmlTitleLinkFixtureAdapter {
-            source_id: "clickworker",
-            crawlability: Crawlability::PublicHtml,
-        })),
-        "oneforma-jobs" => Some(Box::new(HtmlTitleLinkFixtureAdapter {
-            source_id: "oneforma-jobs",
-            crawlability: Crawlability::PublicHtml,
-        })),
-        "telus-ai-community" => Some(Box::new(HtmlTitleLinkFixtureAdapter {
-            source_id: "telus-ai-community",
-            crawlability: Crawlability::PublicHtml,
-        })),
-        "prolific" => Some(Box::new(JsonTitleApplyFixtureAdapter {
-            source_id: "prolific",
-            crawlability: Crawlability::ManualOnly,
-        })),
-        _ => None,
+/// Files written by [`write_captured_fixture`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CapturedFixture {
+    pub bundle_path: PathBuf,
+    pub raw_path: PathBuf,
+    pub snapshot_path: PathBuf,
+    pub sha256: String,
+    pub parsed_records: usize,
+    pub evidence_coverage_percent: f64,
+}
+
+/// Record a live page as the source's sample fixture: the raw body under
+/// `fixtures/<source_id>/sample/raw/`, a `bundle.json` whose parsed records (and evidence) come
+/// from running `adapter` over it, and a `snapshot.json` of those drafts. An existing bundle is
+/// only replaced with `overwrite`.
+pub fn write_captured_fixture(
+    workspace_root: &Path,
+    adapter: &dyn SourceAdapter,
+    page: &FetchedPage,
+    overwrite: bool,
+) -> Result<CapturedFixture> {
+    let source_id = adapter.source_id();
+    let sample_dir = workspace_root.join("fixtures").join(source_id).join("sample");
+    let bundle_path = sample_dir.join("bundle.json");
+    if bundle_path.exists() && !overwrite {
+        anyhow::bail!("{} already exists; pass --force to replace it", bundle_path.display());
     }
+    let ext = extension_for_content_type(&page.content_type);
+    let raw_rel = format!("raw/listing.{ext}");
+    let raw_path = sample_dir.join(&raw_rel);
+    fs::create_dir_all(sample_dir.join("raw")).with_context(|| format!("creating {}", sample_dir.display()))?;
+    fs::write(&raw_path, &page.body).with_context(|| format!("writing {}", raw_path.display()))?;
+    let sha256 = ArtifactStore::sha256_hex(&page.body);
+
+    let mut bundle = FixtureBundle {
+        schema_version: FIXTURE_BUNDLE_SCHEMA_VERSION,
+        fixture_id: "sample".to_string(),
+        source_id: source_id.to_string(),
+        crawlability: adapter.crawlability(),
+        captured_from_url: page.url.clone(),
+        fetched_at: page.fetched_at,
+        extractor_version: adapter.extractor_version().to_string(),
+        raw_artifact: FixtureRawArtifact {
+            content_type: page.content_type.clone(),
+            path: Some(raw_rel),
+            inline_text: Some(decode_text(&page.body, &page.content_type)),
+            sha256: Some(sha256.clone()),
+        },
+        // Adapters that refine parsed records (rather than reading every record from the page)
+        // need one record to fill.
+        artifacts: Vec::new(),
+        parsed_records: vec![FixtureParsedRecord {
+            listing_url: Some(page.url.clone()),
+            ..FixtureParsedRecord::default()
+        }],
+        evidence_coverage_percent: 0.0,
+        notes: Some(format!("captured from {} by rhof-cli capture-fixture", page.url)),
+    };
+    let drafts = adapter
+        .parse_listing(&bundle)
+        .with_context(|| format!("parsing captured page {}", page.url))?
+        .into_iter()
+        .filter(|draft| draft.title.value.is_some())
+        .collect::<Vec<_>>();
+    bundle.parsed_records = drafts.iter().map(draft_to_fixture_record).collect();
+    bundle.evidence_coverage_percent = evidence_coverage_percent(&drafts);
+    bundle.raw_artifact.inline_text = None;
+
+    let bundle_json = serde_json::to_string_pretty(&bundle).context("serializing captured bundle")?;
+    fs::write(&bundle_path, bundle_json + "\n").with_context(|| format!("writing {}", bundle_path.display()))?;
+    let snapshot_path = sample_dir.join("snapshot.json");
+    write_golden_snapshot(&snapshot_path, &golden_drafts(&drafts, adapter.crawlability()))?;
+
+    Ok(CapturedFixture {
+        bundle_path,
+        raw_path,
+        snapshot_path,
+        sha256,
+        parsed_records: drafts.len(),
+        evidence_coverage_percent: bundle.evidence_coverage_percent,
+    })
 }
 
 pub fn generate_adapter_scaffold(
@@ -762,6 +3017,9 @@ pub fn generate_adapter_scaffold(
         source_id,
     )?;
     created.push(adapter_rs.clone());
+    let generated_mod = generated_src_dir.join("mod.rs");
+    append_generated_module_if_missing(&generated_mod, &slug)?;
+    created.push(generated_mod);
 
     write_from_template_if_missing(
         &test_rs,
@@ -852,6 +3110,30 @@ fn to_pascal_case(slug: &str) -> String {
         .collect::<String>()
 }
 
+/// Declare a scaffolded adapter file in `src/generated/mod.rs`, so its `inventory::submit!`
+/// registration is compiled in.
+fn append_generated_module_if_missing(path: &Path, slug: &str) -> Result<()> {
+    let mut current = if path.exists() {
+        fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?
+    } else {
+        "//! Adapters scaffolded by `rhof-cli new-adapter`; each registers itself with the adapter registry.\n".to_string()
+    };
+    let marker = format!("#[path = \"{slug}.rs\"]");
+    if current.contains(&marker) {
+        return Ok(());
+    }
+    let mut module = slug.replace('-', "_");
+    if module.starts_with(|c: char| c.is_ascii_digit()) {
+        module.insert_str(0, "source_");
+    }
+    if !current.ends_with('\n') {
+        current.push('\n');
+    }
+    current.push_str(&format!("\n{marker}\npub mod {module};\n"));
+    fs::write(path, current).with_context(|| format!("writing {}", path.display()))?;
+    Ok(())
+}
+
 fn append_docs_source_stub_if_missing(path: &Path, slug: &str, display_name_input: &str) -> Result<()> {
     let mut current = if path.exists() {
         fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?
@@ -882,17 +3164,6 @@ mod tests {
     use super::*;
     use std::path::PathBuf;
 
-    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-    struct GoldenDraft {
-        title: Option<String>,
-        apply_url: Option<String>,
-        pay_model: Option<String>,
-        pay_rate_min: Option<f64>,
-        pay_rate_max: Option<f64>,
-        currency: Option<String>,
-        crawlability: Crawlability,
-    }
-
     fn workspace_root() -> PathBuf {
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("../..")
@@ -923,99 +3194,877 @@ mod tests {
             .join("snapshot.json")
     }
 
-    fn drafts_to_golden(drafts: &[OpportunityDraft], crawlability: Crawlability) -> Vec<GoldenDraft> {
-        drafts
-            .iter()
-            .map(|d| GoldenDraft {
-                title: d.title.value.clone(),
-                apply_url: d.apply_url.value.clone(),
-                pay_model: d.pay_model.value.clone(),
-                pay_rate_min: d.pay_rate_min.value,
-                pay_rate_max: d.pay_rate_max.value,
-                currency: d.currency.value.clone(),
-                crawlability,
-            })
-            .collect()
-    }
-
-    fn read_snapshot(path: &Path) -> Vec<GoldenDraft> {
-        let text = fs::read_to_string(path).expect("read snapshot");
-        serde_json::from_str(&text).expect("parse snapshot")
-    }
-
     fn assert_all_populated_fields_have_evidence(drafts: &[OpportunityDraft]) {
         for draft in drafts {
-            if draft.title.value.is_some() {
-                assert!(draft.title.evidence.is_some(), "title missing evidence");
-            }
-            if draft.description.value.is_some() {
-                assert!(draft.description.evidence.is_some(), "description missing evidence");
-            }
-            if draft.pay_model.value.is_some() {
-                assert!(draft.pay_model.evidence.is_some(), "pay_model missing evidence");
-            }
-            if draft.currency.value.is_some() {
-                assert!(draft.currency.evidence.is_some(), "currency missing evidence");
-            }
-            if draft.apply_url.value.is_some() {
-                assert!(draft.apply_url.evidence.is_some(), "apply_url missing evidence");
+            for (field, populated, has_evidence) in canonical_evidence_checks(draft) {
+                assert!(!populated || has_evidence, "{field} missing evidence");
             }
         }
+        assert_eq!(evidence_coverage_percent(drafts), 100.0);
     }
 
     #[tokio::test]
     async fn golden_json_snapshot_test_appen_crowdgen() {
         let adapter = appen_crowdgen_adapter();
         let bundle = load_fixture_bundle(fixture_bundle_path("appen-crowdgen")).unwrap();
+        assert_eq!(bundle.extractor_version, adapter.extractor_version());
         let drafts = adapter.parse_listing(&bundle).unwrap();
         assert_all_populated_fields_have_evidence(&drafts);
-        let actual = drafts_to_golden(&drafts, adapter.crawlability());
-        let expected = read_snapshot(&expected_snapshot_path("appen-crowdgen"));
-        assert_eq!(actual, expected);
+        let actual = golden_drafts(&drafts, adapter.crawlability());
+        check_golden_snapshot(expected_snapshot_path("appen-crowdgen"), &actual).unwrap();
     }
 
     #[tokio::test]
     async fn golden_json_snapshot_test_clickworker() {
         let adapter = clickworker_adapter();
         let bundle = load_fixture_bundle(fixture_bundle_path("clickworker")).unwrap();
+        assert_eq!(bundle.extractor_version, adapter.extractor_version());
         let drafts = adapter.parse_listing(&bundle).unwrap();
         assert_all_populated_fields_have_evidence(&drafts);
-        let actual = drafts_to_golden(&drafts, adapter.crawlability());
-        let expected = read_snapshot(&expected_snapshot_path("clickworker"));
-        assert_eq!(actual, expected);
+        let actual = golden_drafts(&drafts, adapter.crawlability());
+        check_golden_snapshot(expected_snapshot_path("clickworker"), &actual).unwrap();
     }
 
     #[tokio::test]
     async fn golden_json_snapshot_test_oneforma_jobs() {
         let adapter = oneforma_jobs_adapter();
         let bundle = load_fixture_bundle(fixture_bundle_path("oneforma-jobs")).unwrap();
+        assert_eq!(bundle.extractor_version, adapter.extractor_version());
         let drafts = adapter.parse_listing(&bundle).unwrap();
         assert_all_populated_fields_have_evidence(&drafts);
-        let actual = drafts_to_golden(&drafts, adapter.crawlability());
-        let expected = read_snapshot(&expected_snapshot_path("oneforma-jobs"));
-        assert_eq!(actual, expected);
+        let actual = golden_drafts(&drafts, adapter.crawlability());
+        check_golden_snapshot(expected_snapshot_path("oneforma-jobs"), &actual).unwrap();
     }
 
     #[tokio::test]
     async fn golden_json_snapshot_test_telus_ai_community() {
         let adapter = telus_ai_community_adapter();
         let bundle = load_fixture_bundle(fixture_bundle_path("telus-ai-community")).unwrap();
+        assert_eq!(bundle.extractor_version, adapter.extractor_version());
         let drafts = adapter.parse_listing(&bundle).unwrap();
         assert_all_populated_fields_have_evidence(&drafts);
-        let actual = drafts_to_golden(&drafts, adapter.crawlability());
-        let expected = read_snapshot(&expected_snapshot_path("telus-ai-community"));
-        assert_eq!(actual, expected);
+        let actual = golden_drafts(&drafts, adapter.crawlability());
+        check_golden_snapshot(expected_snapshot_path("telus-ai-community"), &actual).unwrap();
     }
 
     #[tokio::test]
     async fn golden_json_snapshot_test_prolific_manual_ingestion() {
         let adapter = prolific_manual_adapter();
         let bundle = load_manual_fixture_bundle(manual_fixture_bundle_path("prolific")).unwrap();
+        assert_eq!(bundle.extractor_version, adapter.extractor_version());
+        let drafts = adapter.parse_listing(&bundle).unwrap();
+        assert_all_populated_fields_have_evidence(&drafts);
+        let actual = golden_drafts(&drafts, adapter.crawlability());
+        check_golden_snapshot(expected_snapshot_path("prolific"), &actual).unwrap();
+    }
+
+    /// Serves each `(path_and_query, body)` page with 200 and anything else with 404, until the
+    /// test ends.
+    async fn spawn_listing_server(pages: Vec<(&'static str, String)>) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !String::from_utf8_lossy(&request).contains("\r\n\r\n") {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let request = String::from_utf8_lossy(&request);
+                let path = request.split_whitespace().nth(1).unwrap_or("/");
+                let (status, body) = match pages.iter().find(|(p, _)| *p == path) {
+                    Some((_, body)) => ("200 OK", body.as_str()),
+                    None => ("404 Not Found", ""),
+                };
+                let response = format!(
+                    "HTTP/1.1 {status}\r\ncontent-type: text/html\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        base
+    }
+
+    fn live_test_context() -> (HttpFetcher, AdapterContext) {
+        let http = HttpFetcher::new(rhof_storage::HttpClientConfig::default()).unwrap();
+        (http, AdapterContext { run_id: Uuid::nil(), fetched_at: Utc::now(), ignore_robots: false })
+    }
+
+    #[tokio::test]
+    async fn html_adapter_fetches_listing_pages_live() {
+        let base = spawn_listing_server(vec![
+            ("/jobs", "<h1>Data Annotator</h1>".to_string()),
+            ("/jobs/remote", "<h1>Remote Rater</h1>".to_string()),
+            ("/robots.txt", "User-agent: *\nDisallow: /private\n".to_string()),
+            ("/private/job", "<h1>Hidden</h1>".to_string()),
+        ])
+        .await;
+        let (http, ctx) = live_test_context();
+        let adapter = clickworker_adapter();
+        let targets = ["/jobs", "/jobs/remote"]
+            .map(|path| ListingTarget { url: format!("{base}{path}"), pagination: Pagination::default() });
+
+        let pages = adapter.fetch_listing(&http, &ctx, &targets).await.unwrap();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].url, format!("{base}/jobs"));
+        assert_eq!(pages[1].url, format!("{base}/jobs/remote"));
+        assert_eq!(pages[0].body, b"<h1>Data Annotator</h1>");
+        assert_eq!(pages[0].content_type, "text/html");
+        assert!(pages.iter().all(|page| page.fetched_at >= ctx.fetched_at));
+
+        let missing = [DetailTarget { url: format!("{base}/gone") }];
+        let err = adapter.fetch_detail(&http, &ctx, &missing).await.unwrap_err();
+        assert!(err.to_string().contains("/gone"), "{err}");
+        assert!(!err.is_blocked_by_robots());
+
+        let private = [DetailTarget { url: format!("{base}/private/job") }];
+        let err = adapter.fetch_detail(&http, &ctx, &private).await.unwrap_err();
+        assert!(err.is_blocked_by_robots(), "{err}");
+        let overridden = AdapterContext { ignore_robots: true, ..ctx.clone() };
+        assert_eq!(adapter.fetch_detail(&http, &overridden, &private).await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn live_pages_keep_the_server_content_type_and_charset() {
+        let response = |content_type: Option<&'static str>| {
+            let mut headers = reqwest::header::HeaderMap::new();
+            if let Some(content_type) = content_type {
+                headers.insert(reqwest::header::CONTENT_TYPE, reqwest::header::HeaderValue::from_static(content_type));
+            }
+            FetchedResponse {
+                status: reqwest::StatusCode::OK,
+                final_url: "https://example.com/feed".to_string(),
+                // "Café" in ISO-8859-1.
+                body: b"<title>Caf\xe9</title>".to_vec(),
+                validators: Default::default(),
+                version: reqwest::Version::HTTP_11,
+                headers,
+                request_headers: Default::default(),
+            }
+        };
+        let page = |content_type| FetchedPage::from_response(response(content_type), "application/rss+xml").content_type;
+
+        assert_eq!(page(None), "application/rss+xml");
+        assert_eq!(page(Some("text/xml; charset=ISO-8859-1")), "text/xml; charset=ISO-8859-1");
+        assert_eq!(page(Some("text/plain; charset=ISO-8859-1")), "application/rss+xml; charset=ISO-8859-1");
+        assert_eq!(page(Some("application/octet-stream")), "application/rss+xml");
+
+        let latin = FetchedPage::from_response(response(Some("text/xml; charset=ISO-8859-1")), "application/rss+xml");
+        let bundle = listing_page_bundle("remote-feed", Crawlability::Rss, "rss-v1", &latin);
+        assert_eq!(bundle.raw_artifact.inline_text.as_deref(), Some("<title>Café</title>"));
+    }
+
+    #[test]
+    fn adapter_errors_say_whether_to_retry_skip_or_flag_for_maintenance() {
+        let url = "https://example.com/jobs";
+        let unavailable = AdapterError::fetch_failed(url, FetchError::HttpStatus { status: 503, url: url.to_string() });
+        assert!(matches!(unavailable, AdapterError::FetchFailed { retryable: true, .. }));
+        assert_eq!(unavailable.action(), FailureAction::Retry);
+        let missing = AdapterError::fetch_failed(url, FetchError::HttpStatus { status: 404, url: url.to_string() });
+        assert_eq!(missing.action(), FailureAction::Skip);
+        assert_eq!(missing.to_string(), "fetching https://example.com/jobs: http status 404 for https://example.com/jobs");
+        let robots = AdapterError::fetch_failed(url, FetchError::BlockedByRobots { url: url.to_string() });
+        assert!(robots.is_blocked_by_robots());
+        assert_eq!(robots.action(), FailureAction::Skip);
+
+        let page = FetchedPage {
+            url: url.to_string(),
+            content_type: "text/html".to_string(),
+            body: b"<h1>Rater</h1>".to_vec(),
+            fetched_at: Utc::now(),
+            not_modified: false,
+        };
+        let bundle = listing_page_bundle("appen-crowdgen", Crawlability::PublicHtml, "appen-crowdgen-v1", &page);
+        let err = clickworker_adapter().parse_listing(&bundle).unwrap_err();
+        assert!(matches!(err, AdapterError::SourceMismatch { .. }), "{err}");
+        assert_eq!(err.action(), FailureAction::Maintenance);
+        assert_eq!(AdapterError::SchemaMismatch("no items".to_string()).action(), FailureAction::Maintenance);
+
+        // The pipeline sees adapter errors wrapped in context.
+        let wrapped = anyhow::Error::from(unavailable).context("syncing source clickworker");
+        assert_eq!(AdapterError::action_of(&wrapped), Some(FailureAction::Retry));
+        assert_eq!(AdapterError::action_of(&anyhow::anyhow!("disk full")), None);
+    }
+
+    #[tokio::test]
+    async fn html_adapter_walks_paginated_listings_up_to_the_limit() {
+        let base = spawn_listing_server(vec![
+            ("/numbered?page=1", "page one".to_string()),
+            ("/numbered?page=2", "page two".to_string()),
+            ("/clamped/1", "first".to_string()),
+            ("/clamped/2", "last".to_string()),
+            ("/clamped/3", "last".to_string()),
+            ("/linked", r#"<a class="next" href="/linked/2">next</a>"#.to_string()),
+            ("/linked/2", r#"<a class="next" href="3">next</a>"#.to_string()),
+            ("/linked/3", r#"<a class="next" href="/linked">again</a>"#.to_string()),
+        ])
+        .await;
+        let (http, ctx) = live_test_context();
+        let adapter = clickworker_adapter();
+        let fetch = |url: String, pagination: Pagination| {
+            let (http, ctx, adapter) = (&http, &ctx, &adapter);
+            async move {
+                let pages = adapter.fetch_listing(http, ctx, &[ListingTarget { url, pagination }]).await.unwrap();
+                pages.into_iter().map(|page| page.url).collect::<Vec<_>>()
+            }
+        };
+
+        // Numbered pages stop at the first 404 ...
+        let urls = fetch(format!("{base}/numbered?page={{page}}"), Pagination::default()).await;
+        assert_eq!(urls, [format!("{base}/numbered?page=1"), format!("{base}/numbered?page=2")]);
+        // ... or when a page repeats the previous one.
+        let urls = fetch(format!("{base}/clamped/{{page}}"), Pagination::default()).await;
+        assert_eq!(urls.len(), 2);
+
+        let linked = Pagination { next_selector: Some("a.next".to_string()), ..Pagination::default() };
+        let urls = fetch(format!("{base}/linked"), linked.clone()).await;
+        assert_eq!(urls, [format!("{base}/linked"), format!("{base}/linked/2"), format!("{base}/linked/3")]);
+        let urls = fetch(format!("{base}/linked"), Pagination { max_pages: 2, ..linked }).await;
+        assert_eq!(urls.len(), 2);
+        // Without a next selector only the listing URL itself is fetched.
+        assert_eq!(fetch(format!("{base}/linked"), Pagination::default()).await.len(), 1);
+
+        assert!(Pagination { max_pages: 0, ..Pagination::default() }.validate().is_err());
+        assert!(Pagination { next_selector: Some("a[".to_string()), ..Pagination::default() }.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn health_check_flags_dead_urls_and_missing_sentinels() {
+        let base = spawn_listing_server(vec![
+            ("/jobs?page=1", "<h1>Data Annotator</h1>".to_string()),
+            ("/redesigned", "<div class=\"hero\">We moved!</div>".to_string()),
+            ("/cards", r#"<ul><li class="card"><h2>Rater</h2></li></ul>"#.to_string()),
+        ])
+        .await;
+        let (http, ctx) = live_test_context();
+        let target = |path: &str| [ListingTarget { url: format!("{base}{path}"), pagination: Pagination::default() }];
+        let adapter = clickworker_adapter();
+
+        let health = adapter.health_check(&http, &ctx, &target("/jobs?page={page}")).await;
+        assert_eq!(health.status, HealthStatus::Healthy, "{health:?}");
+        assert_eq!(health.url, Some(format!("{base}/jobs?page=1")));
+        assert_eq!(health.http_status, Some(200));
+        assert!(!health.is_broken());
+
+        let health = adapter.health_check(&http, &ctx, &target("/redesigned")).await;
+        assert_eq!(health.status, HealthStatus::SentinelMissing);
+        assert_eq!(health.sentinel.as_deref(), Some("h1"));
+        assert!(health.is_broken());
+
+        let health = adapter.health_check(&http, &ctx, &target("/gone")).await;
+        assert_eq!((health.status, health.http_status), (HealthStatus::Unreachable, Some(404)));
+        assert!(health.message.unwrap().contains("/gone"));
+
+        let selectors: HtmlSelectors = serde_yaml::from_str("item: li.card\ntitle: h2\n").unwrap();
+        let configured = ConfigurableHtmlAdapter::new("cards", selectors).unwrap();
+        assert_eq!(configured.health_check(&http, &ctx, &target("/cards")).await.status, HealthStatus::Healthy);
+        assert_eq!(configured.health_check(&http, &ctx, &target("/redesigned")).await.status, HealthStatus::SentinelMissing);
+
+        assert_eq!(adapter.health_check(&http, &ctx, &[]).await.status, HealthStatus::Skipped);
+        let manual = prolific_manual_adapter().health_check(&http, &ctx, &target("/cards")).await;
+        assert_eq!(manual.status, HealthStatus::Skipped);
+    }
+
+    #[tokio::test]
+    async fn api_adapter_follows_cursor_pages_and_maps_results() {
+        let base = spawn_listing_server(vec![
+            ("/v1/tasks", r#"{"data":[{"id":7,"title":"Audio Rating","pay":{"min":"10","max":14}},{"id":8}],"next":"/v1/tasks?cursor=b"}"#.to_string()),
+            ("/v1/tasks?cursor=b", r#"{"data":[{"id":9,"title":"Image Tagging","url":"https://tasks.example/9"}],"next":null}"#.to_string()),
+        ])
+        .await;
+        let config: ApiSourceConfig = serde_yaml::from_str(
+            "auth: { header: X-Api-Key, secret_env: RHOF_ADAPTER_TEST_API_KEY }\nitems_pointer: /data\nnext_pointer: /next\nfields: { external_id: /id, title: /title, pay_rate_min: /pay/min, pay_rate_max: /pay/max, apply_url: /url }\n",
+        )
+        .unwrap();
+        let adapter = ApiAdapter::new("tasks-api", Crawlability::Api, config).unwrap();
+        let (http, ctx) = live_test_context();
+        let targets = [ListingTarget { url: format!("{base}/v1/tasks"), pagination: Pagination::default() }];
+
+        let err = adapter.fetch_listing(&http, &ctx, &targets).await.unwrap_err();
+        assert!(err.to_string().contains("RHOF_ADAPTER_TEST_API_KEY is not set"), "{err}");
+
+        std::env::set_var("RHOF_ADAPTER_TEST_API_KEY", "k");
+        let pages = adapter.fetch_listing(&http, &ctx, &targets).await.unwrap();
+        assert_eq!(pages.len(), 2);
+        let bundles = pages
+            .iter()
+            .map(|page| listing_page_bundle("tasks-api", Crawlability::Api, adapter.extractor_version(), page))
+            .collect::<Vec<_>>();
+        let drafts = adapter.parse_listing_pages(&bundles).unwrap();
+        assert_eq!(drafts.len(), 2);
+        assert_all_populated_fields_have_evidence(&drafts);
+        assert_eq!(drafts[0].external_id.as_deref(), Some("7"));
+        assert_eq!(drafts[0].pay_rate_min.value, Some(10.0));
+        assert_eq!(drafts[0].pay_rate_max.value, Some(14.0));
+        assert_eq!(drafts[0].pay_rate_min.evidence.as_ref().unwrap().selector_or_pointer, "/data/0/pay/min");
+        assert_eq!(drafts[1].apply_url.value.as_deref(), Some("https://tasks.example/9"));
+        assert_eq!(drafts[1].title.evidence.as_ref().unwrap().source_url, format!("{base}/v1/tasks?cursor=b"));
+
+        let bad: ApiSourceConfig = serde_yaml::from_str("fields: { title: name }").unwrap();
+        assert!(ApiAdapter::new("tasks-api", Crawlability::Api, bad).is_err());
+    }
+
+    #[tokio::test]
+    async fn job_board_adapters_map_greenhouse_and_lever_postings() {
+        let base = spawn_listing_server(vec![
+            (
+                "/v1/boards/acme-ai/jobs?content=true",
+                r#"{"jobs":[{"id":4011,"title":"AI Trainer (Spanish)","absolute_url":"https://boards.greenhouse.io/acme-ai/jobs/4011","location":{"name":"Remote - LATAM"},"content":"&lt;p&gt;Rate &amp;amp; review &lt;b&gt;model&lt;/b&gt; answers.&lt;/p&gt;"},{"id":4012,"title":""}],"meta":{"total":2}}"#.to_string(),
+            ),
+            (
+                "/v0/postings/labelco?mode=json",
+                r#"[{"id":"b6f1","text":"Data Labeler","hostedUrl":"https://jobs.lever.co/labelco/b6f1","applyUrl":"https://jobs.lever.co/labelco/b6f1/apply","descriptionPlain":"Label images.","categories":{"location":"United States","commitment":"Part-time"},"salaryRange":{"min":18,"max":22,"currency":"usd","interval":"per-hour-wage"}}]"#.to_string(),
+            ),
+        ])
+        .await;
+        let (http, ctx) = live_test_context();
+        let board = |provider, token: &str| JobBoardConfig { provider, board_token: token.to_string(), api_base: Some(base.clone()) };
+
+        let greenhouse = JobBoardAdapter::new("acme-ai", board(JobBoardProvider::Greenhouse, "acme-ai")).unwrap();
+        assert_eq!(greenhouse.extractor_version(), "acme-ai-greenhouse-v1");
+        let pages = greenhouse.fetch_listing(&http, &ctx, &[]).await.unwrap();
+        let bundle = listing_page_bundle("acme-ai", Crawlability::Api, greenhouse.extractor_version(), &pages[0]);
+        let drafts = greenhouse.parse_listing(&bundle).unwrap();
+        assert_eq!(drafts.len(), 1, "untitled postings are dropped");
+        assert_all_populated_fields_have_evidence(&drafts);
+        assert_eq!(drafts[0].external_id.as_deref(), Some("4011"));
+        assert_eq!(drafts[0].description.value.as_deref(), Some("Rate & review model answers."));
+        assert_eq!(drafts[0].geo_constraints.value.as_deref(), Some("Remote - LATAM"));
+        assert_eq!(drafts[0].title.evidence.as_ref().unwrap().selector_or_pointer, "/jobs/0/title");
+        assert_eq!(greenhouse.health_check(&http, &ctx, &[]).await.status, HealthStatus::Healthy);
+
+        let lever = JobBoardAdapter::new("labelco", board(JobBoardProvider::Lever, "labelco")).unwrap();
+        let pages = lever.fetch_listing(&http, &ctx, &[]).await.unwrap();
+        let bundle = listing_page_bundle("labelco", Crawlability::Api, lever.extractor_version(), &pages[0]);
+        let drafts = lever.parse_listing(&bundle).unwrap();
+        assert_all_populated_fields_have_evidence(&drafts);
+        let draft = &drafts[0];
+        assert_eq!(draft.apply_url.value.as_deref(), Some("https://jobs.lever.co/labelco/b6f1/apply"));
+        assert_eq!(draft.detail_url.as_deref(), Some("https://jobs.lever.co/labelco/b6f1"));
+        assert_eq!((draft.pay_rate_min.value, draft.pay_rate_max.value), (Some(18.0), Some(22.0)));
+        assert_eq!(draft.currency.value.as_deref(), Some("USD"));
+        assert_eq!(draft.pay_unit.value.as_deref(), Some("hour"));
+        assert_eq!(draft.one_off_vs_ongoing.value.as_deref(), Some("Part-time"));
+        assert_eq!(draft.pay_rate_min.evidence.as_ref().unwrap().selector_or_pointer, "/0/salaryRange/min");
+
+        let gone = JobBoardAdapter::new("gone", board(JobBoardProvider::Lever, "gone")).unwrap();
+        assert_eq!(gone.health_check(&http, &ctx, &[]).await.status, HealthStatus::Unreachable);
+        assert!(board(JobBoardProvider::Greenhouse, "acme/../x").validate().is_err());
+        let default_host = JobBoardConfig { api_base: None, ..board(JobBoardProvider::Greenhouse, "acme-ai") };
+        assert_eq!(default_host.listing_url(), "https://boards-api.greenhouse.io/v1/boards/acme-ai/jobs?content=true");
+    }
+
+    #[tokio::test]
+    async fn generic_json_adapter_maps_fields_by_json_path_with_pointer_evidence() {
+        let next = "/feed?cursor=2";
+        let base = spawn_listing_server(vec![
+            (
+                "/feed",
+                format!(
+                    r#"{{"data":{{"jobs":[{{"job id":"a1","title":"Search Rater","pay":{{"min":"14.5","max":20,"unit":"per hour","currency":"USD"}},"tags":["Fluent English","Gmail account"],"links":{{"apply":"https://example.test/a1/apply"}},"where":"US/Canada"}},{{"title":null}}]}},"paging":{{"next":"{next}"}}}}"#
+                ),
+            ),
+            (next, r#"{"data":{"jobs":[{"job id":"b2","title":"Audio Transcriber","tags":"Headphones"}]},"paging":{}}"#.to_string()),
+        ])
+        .await;
+        let (http, ctx) = live_test_context();
+        let mapping: JsonMapping = serde_yaml::from_str(
+            r#"
+items: $.data.jobs[*]
+next: $.paging.next
+fields:
+  title: $.title
+  external_id: $['job id']
+  pay_rate_min: $.pay.min
+  pay_rate_max: /pay/max
+  pay_unit: $.pay.unit
+  currency: $.pay.currency
+  requirements: $.tags
+  apply_url: $.links.apply
+  geo_constraints: $.where
+"#,
+        )
+        .unwrap();
+        let adapter = GenericJsonAdapter::new("jsonfeed", Crawlability::Api, &mapping).unwrap();
+        assert_eq!(adapter.extractor_version(), "jsonfeed-json-v1");
+
+        let targets = [ListingTarget { url: format!("{base}/feed"), pagination: Pagination::default() }];
+        let pages = adapter.fetch_listing(&http, &ctx, &targets).await.unwrap();
+        assert_eq!(pages.len(), 2, "the `next` cursor is followed");
+        let first = adapter
+            .parse_listing(&listing_page_bundle("jsonfeed", Crawlability::Api, adapter.extractor_version(), &pages[0]))
+            .unwrap();
+        assert_eq!(first.len(), 1, "untitled results are dropped");
+        assert_all_populated_fields_have_evidence(&first);
+        let draft = &first[0];
+        assert_eq!(draft.external_id.as_deref(), Some("a1"));
+        assert_eq!((draft.pay_rate_min.value, draft.pay_rate_max.value), (Some(14.5), Some(20.0)));
+        assert_eq!(draft.pay_unit.value.as_deref(), Some("hour"));
+        assert_eq!(draft.requirements.value.as_deref(), Some(&["Fluent English".to_string(), "Gmail account".to_string()][..]));
+        assert_eq!(draft.geo_constraints.evidence.as_ref().unwrap().selector_or_pointer, "/data/jobs/0/where");
+        assert_eq!(draft.pay_rate_min.evidence.as_ref().unwrap().selector_or_pointer, "/data/jobs/0/pay/min");
+        let second = adapter
+            .parse_listing(&listing_page_bundle("jsonfeed", Crawlability::Api, adapter.extractor_version(), &pages[1]))
+            .unwrap();
+        assert_eq!(second[0].requirements.value.as_deref(), Some(&["Headphones".to_string()][..]));
+        assert_eq!(adapter.health_check(&http, &ctx, &targets).await.status, HealthStatus::Healthy);
+
+        assert_eq!(json_path_to_pointer("$['a/b'].c~d[3]").as_deref(), Ok("/a~1b/c~0d/3"));
+        assert!(json_path_to_pointer("$.jobs[*].title").is_err());
+        assert!(json_path_to_pointer("jobs.title").is_err());
+        let moved = JsonMapping { items: "$.results[*]".to_string(), ..mapping };
+        let moved = GenericJsonAdapter::new("jsonfeed", Crawlability::Api, &moved).unwrap();
+        let bundle = listing_page_bundle("jsonfeed", Crawlability::Api, moved.extractor_version(), &pages[0]);
+        assert!(matches!(moved.parse_listing(&bundle), Err(AdapterError::SchemaMismatch(_))));
+    }
+
+    #[tokio::test]
+    async fn rss_adapters_map_upwork_and_weworkremotely_items() {
+        let base = spawn_listing_server(vec![
+            (
+                "/ab/feed/jobs/rss?q=annotation",
+                r#"<?xml version="1.0" encoding="UTF-8"?><rss version="2.0"><channel><title>Upwork</title>
+<item><title><![CDATA[Image annotation for retail shelves - Upwork]]></title>
+<link>https://www.upwork.com/jobs/~01abc?source=rss</link><guid>https://www.upwork.com/jobs/~01abc</guid>
+<description><![CDATA[Label shelf photos.<br /><br /><b>Hourly Range</b>: $15.00-$30.00<br /><b>Country</b>: United States<br /><b>Skills</b>: Data Entry, Annotation<br />]]></description></item>
+<item><title></title><description>no title</description></item>
+</channel></rss>"#.to_string(),
+            ),
+            (
+                "/categories/remote-customer-support-jobs.rss",
+                r#"<?xml version="1.0" encoding="UTF-8"?><rss version="2.0"><channel><title>WWR</title>
+<item><title>Helpdesk Co: Support Agent</title><region>Anywhere in the World</region><type>Contract</type>
+<link>https://weworkremotely.com/remote-jobs/helpdesk-co-support-agent</link>
+<description>&lt;p&gt;Answer tickets.&lt;/p&gt;&lt;p&gt;Pay: $20/hr&lt;/p&gt;</description></item>
+</channel></rss>"#.to_string(),
+            ),
+        ])
+        .await;
+        let (http, ctx) = live_test_context();
+
+        let upwork = upwork_rss_adapter();
+        let targets = [ListingTarget { url: format!("{base}/ab/feed/jobs/rss?q=annotation"), pagination: Pagination::default() }];
+        let pages = upwork.fetch_listing(&http, &ctx, &targets).await.unwrap();
+        let bundle = listing_page_bundle("upwork", Crawlability::Rss, upwork.extractor_version(), &pages[0]);
+        let drafts = upwork.parse_listing(&bundle).unwrap();
+        assert_eq!(drafts.len(), 1, "untitled items are dropped");
+        assert_all_populated_fields_have_evidence(&drafts);
+        let draft = &drafts[0];
+        assert_eq!(draft.title.value.as_deref(), Some("Image annotation for retail shelves"));
+        assert_eq!(draft.external_id.as_deref(), Some("https://www.upwork.com/jobs/~01abc"));
+        assert_eq!((draft.pay_rate_min.value, draft.pay_rate_max.value), (Some(15.0), Some(30.0)));
+        assert_eq!(draft.pay_unit.value.as_deref(), Some("hour"));
+        assert_eq!(draft.currency.value.as_deref(), Some("USD"));
+        assert_eq!(draft.geo_constraints.value.as_deref(), Some("United States"));
+        assert_eq!(draft.requirements.value.as_deref(), Some(&["Data Entry".to_string(), "Annotation".to_string()][..]));
+        assert_eq!(draft.pay_rate_min.evidence.as_ref().unwrap().selector_or_pointer, "item[1]/description");
+        assert_eq!(upwork.health_check(&http, &ctx, &targets).await.status, HealthStatus::Healthy);
+
+        let wwr = weworkremotely_adapter();
+        let targets = [ListingTarget { url: format!("{base}/categories/remote-customer-support-jobs.rss"), pagination: Pagination::default() }];
+        let pages = wwr.fetch_listing(&http, &ctx, &targets).await.unwrap();
+        let bundle = listing_page_bundle("weworkremotely", Crawlability::Rss, wwr.extractor_version(), &pages[0]);
+        let drafts = wwr.parse_listing(&bundle).unwrap();
+        assert_all_populated_fields_have_evidence(&drafts);
+        let draft = &drafts[0];
+        assert_eq!(draft.title.value.as_deref(), Some("Support Agent"));
+        assert_eq!(draft.description.value.as_deref(), Some("Answer tickets. Pay: $20/hr"));
+        assert_eq!(draft.pay_rate_min.value, Some(20.0));
+        assert_eq!(draft.geo_constraints.value.as_deref(), Some("Anywhere in the World"));
+        assert_eq!(draft.one_off_vs_ongoing.value.as_deref(), Some("Contract"));
+        assert_eq!(draft.geo_constraints.evidence.as_ref().unwrap().selector_or_pointer, "item[1]/region");
+        assert!(adapter_for_source("weworkremotely").is_some());
+    }
+
+    #[test]
+    fn pay_parsing_detects_currency_codes_symbols_and_separators() {
+        let currency = |text| parse_pay_fields(text).currency;
+        assert_eq!(currency("$15-$20/hr").as_deref(), Some("USD"));
+        assert_eq!(currency("€12,50 per task").as_deref(), Some("EUR"));
+        assert_eq!(currency("£9/hr").as_deref(), Some("GBP"));
+        assert_eq!(currency("₹1,500 per month").as_deref(), Some("INR"));
+        assert_eq!(currency("C$25 hourly").as_deref(), Some("CAD"));
+        assert_eq!(currency("$30 AUD/hr").as_deref(), Some("AUD"), "an explicit code wins over `$`");
+        assert_eq!(currency("20 eur per study").as_deref(), Some("EUR"));
+        assert_eq!(currency("10 Euros fixed").as_deref(), Some("EUR"));
+        assert_eq!(currency("Competitive, paid via arcade credits"), None, "codes must be whole words");
+
+        assert_eq!(parse_pay_fields("€12,50-€15 per task").rate_min, Some(12.5));
+        assert_eq!(parse_pay_fields("₹1,500-₹2,000 per month").rate_max, Some(2000.0));
+    }
+
+    #[test]
+    fn pay_parsing_captures_the_unit_a_rate_pays_for() {
+        let unit = |text| parse_pay_fields(text).unit;
+        assert_eq!(unit("$15-$20/hr").as_deref(), Some("hour"));
+        assert_eq!(unit("$0.02 per word").as_deref(), Some("word"));
+        assert_eq!(unit("£0.10/min of audio").as_deref(), Some("minute"));
+        assert_eq!(unit("$8 per study (about 45 minutes)").as_deref(), Some("study"));
+        assert_eq!(unit("₹20,000 per month").as_deref(), Some("month"));
+        assert_eq!(unit("$1.50 per task, paid weekly").as_deref(), Some("task"), "the first unit named wins");
+        assert_eq!(unit("Hours").as_deref(), Some("hour"));
+        assert_eq!(unit("Competitive"), None);
+        assert_eq!(parse_pay_fields("$0.02 per word").model, None, "the unit does not invent a pay model");
+    }
+
+    #[test]
+    fn listing_url_templates_expand_every_variable_combination() {
+        let variables = BTreeMap::from([
+            ("country".to_string(), vec!["us".to_string(), "gb".to_string()]),
+            ("category".to_string(), vec!["data".to_string(), "audio".to_string()]),
+        ]);
+        let template = "https://example.com/{country}/jobs?cat={category}&page={page}&c={country}";
+        assert_eq!(url_placeholders(template), ["country", "category", "page"]);
+        assert_eq!(
+            expand_listing_url(template, &variables).unwrap(),
+            [
+                "https://example.com/us/jobs?cat=data&page={page}&c=us",
+                "https://example.com/us/jobs?cat=audio&page={page}&c=us",
+                "https://example.com/gb/jobs?cat=data&page={page}&c=gb",
+                "https://example.com/gb/jobs?cat=audio&page={page}&c=gb",
+            ]
+        );
+        assert_eq!(expand_listing_url("https://example.com/jobs", &variables).unwrap(), ["https://example.com/jobs"]);
+        let err = expand_listing_url("https://example.com/{region}/jobs", &variables).unwrap_err();
+        assert_eq!(err, "listing URL `https://example.com/{region}/jobs` uses `{region}` but variables has no values for `region`");
+    }
+
+    #[test]
+    fn parse_listing_pages_merges_pages_without_repeats() {
+        let adapter = clickworker_adapter();
+        let bundle = load_fixture_bundle(fixture_bundle_path("clickworker")).unwrap();
+        let single = adapter.parse_listing(&bundle).unwrap();
+        let mut second_page = bundle.clone();
+        let mut extra = second_page.parsed_records[0].clone();
+        extra.detail_url = Some("https://www.clickworker.com/jobs/other".to_string());
+        second_page.parsed_records.push(extra);
+
+        let merged = adapter.parse_listing_pages(&[bundle, second_page]).unwrap();
+        assert_eq!(merged.len(), single.len() + 1);
+    }
+
+    #[test]
+    fn detail_page_fields_merge_into_listing_draft_with_evidence() {
+        let adapter = clickworker_adapter();
+        let listing_bundle = load_fixture_bundle(fixture_bundle_path("clickworker")).unwrap();
+        let mut listing = adapter.parse_listing(&listing_bundle).unwrap().remove(0);
+        let page = FetchedPage {
+            url: "https://www.clickworker.com/clickworker-job/ai-data-contributor/".to_string(),
+            content_type: "text/html".to_string(),
+            body: br#"<h1>Renamed On Detail</h1><div class="pay">$18-$22/hr USD</div><div class="geo">US, CA</div><ul class="requirements"><li>Laptop</li></ul>"#.to_vec(),
+            fetched_at: Utc::now(),
+            not_modified: false,
+        };
+        let bundle = detail_page_bundle("clickworker", adapter.crawlability(), "clickworker-v1", &page);
+        let detail = adapter.parse_detail(&bundle).unwrap().remove(0);
+        assert_all_populated_fields_have_evidence(std::slice::from_ref(&detail));
+
+        merge_detail_draft(&mut listing, detail);
+        assert_eq!(listing.title.value.as_deref(), Some("Clickworker AI Data Contributor"));
+        assert_eq!(listing.pay_rate_min.value, Some(18.0));
+        assert_eq!(listing.geo_constraints.value.as_deref(), Some("US, CA"));
+        assert_eq!(listing.requirements.value, Some(vec!["Laptop".to_string()]));
+        let evidence = listing.requirements.evidence.as_ref().unwrap();
+        assert_eq!(evidence.source_url, page.url);
+        assert_eq!(evidence.selector_or_pointer, ".requirements li");
+        assert_eq!(evidence.raw_artifact_id, deterministic_raw_artifact_id_for_bundle(&bundle));
+        // Fields the detail page doesn't carry keep the listing's values and evidence.
+        assert_eq!(listing.payment_methods.value, Some(vec!["PayPal".to_string()]));
+        assert_eq!(listing.payment_methods.evidence.as_ref().unwrap().source_url, listing_bundle.captured_from_url);
+    }
+
+    #[test]
+    fn bundle_migration_upgrades_legacy_and_rejects_newer_schemas() {
+        let root = tempfile::tempdir().unwrap();
+        let mut legacy: JsonValue =
+            serde_json::from_str(&fs::read_to_string(fixture_bundle_path("clickworker")).unwrap()).unwrap();
+        let legacy_map = legacy.as_object_mut().unwrap();
+        legacy_map.remove("schema_version");
+        legacy_map.remove("notes");
+        legacy_map.remove("evidence_coverage_percent");
+        legacy["raw_artifact"] = serde_json::json!({ "content_type": "text/html", "inline_text": "<h1>Legacy</h1>" });
+        let path = root.path().join("bundle.json");
+        fs::write(&path, legacy.to_string()).unwrap();
+        let bundle = load_fixture_bundle(&path).unwrap();
+        assert_eq!(bundle.schema_version, FIXTURE_BUNDLE_SCHEMA_VERSION);
+        assert_eq!(bundle.evidence_coverage_percent, 0.0);
+        assert!(bundle.raw_artifact.path.is_none());
+
+        legacy["schema_version"] = serde_json::json!(FIXTURE_BUNDLE_SCHEMA_VERSION + 1);
+        fs::write(&path, legacy.to_string()).unwrap();
+        let err = format!("{:#}", load_fixture_bundle(&path).unwrap_err());
+        assert!(err.contains("newer than this build supports"), "{err}");
+
+        let manifest = extractor_manifest();
+        let clickworker = manifest.iter().find(|m| m.source_id == "clickworker").unwrap();
+        assert_eq!(clickworker.extractor_version, "clickworker-v1");
+        assert_eq!(clickworker.bundle_schema_version, FIXTURE_BUNDLE_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn fixture_bundle_validation_checks_hash_coverage_and_evidence() {
+        for source_id in ["appen-crowdgen", "clickworker", "oneforma-jobs", "prolific", "telus-ai-community"] {
+            let issues = validate_fixture_bundle(fixture_bundle_path(source_id)).unwrap();
+            assert!(issues.is_empty(), "{source_id}: {issues:?}");
+        }
+        let manual = workspace_root().join("manual/prolific/sample.json");
+        assert!(validate_fixture_bundle(manual).unwrap().is_empty());
+
+        let root = tempfile::tempdir().unwrap();
+        let sample = fixture_bundle_path("clickworker");
+        fs::create_dir_all(root.path().join("raw")).unwrap();
+        fs::copy(sample.with_file_name("raw").join("listing.html"), root.path().join("raw/listing.html")).unwrap();
+        let mut bundle: JsonValue = serde_json::from_str(&fs::read_to_string(&sample).unwrap()).unwrap();
+        bundle["raw_artifact"]["sha256"] = serde_json::json!("00");
+        bundle["evidence_coverage_percent"] = serde_json::json!(80.0);
+        bundle["extractor_version"] = serde_json::json!("");
+        bundle["parsed_records"][0]["geo_constraints"]["selector_or_pointer"] = serde_json::json!(".region");
+        let path = root.path().join("bundle.json");
+        fs::write(&path, bundle.to_string()).unwrap();
+
+        let issues = validate_fixture_bundle(&path).unwrap();
+        let fields = issues.iter().map(|issue| issue.field.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            [
+                "extractor_version",
+                "raw_artifact.sha256",
+                "evidence_coverage_percent",
+                "parsed_records[0].geo_constraints"
+            ],
+            "{issues:?}"
+        );
+        assert_eq!(json_path_to_pointer("$.audience.country").as_deref(), Ok("/audience/country"));
+        assert_eq!(json_path_to_pointer("$.items[0].name").as_deref(), Ok("/items/0/name"));
+    }
+
+    #[test]
+    fn bundle_artifacts_key_evidence_by_url_and_are_validated() {
+        let root = tempfile::tempdir().unwrap();
+        let sample = fixture_bundle_path("clickworker");
+        fs::create_dir_all(root.path().join("raw")).unwrap();
+        fs::copy(sample.with_file_name("raw").join("listing.html"), root.path().join("raw/listing.html")).unwrap();
+        fs::write(root.path().join("raw/detail.html"), r#"<div class="region">US only</div>"#).unwrap();
+        let detail_url = "https://www.clickworker.com/jobs/ai-data-contributor";
+        let mut bundle: JsonValue = serde_json::from_str(&fs::read_to_string(&sample).unwrap()).unwrap();
+        bundle["artifacts"] = serde_json::json!([
+            { "role": "detail", "url": detail_url, "content_type": "text/html", "path": "raw/detail.html" },
+        ]);
+        let geo = &mut bundle["parsed_records"][0]["geo_constraints"];
+        geo["selector_or_pointer"] = serde_json::json!(".region");
+        geo["artifact_url"] = serde_json::json!(detail_url);
+        let path = root.path().join("bundle.json");
+        fs::write(&path, bundle.to_string()).unwrap();
+        assert!(validate_fixture_bundle(&path).unwrap().is_empty());
+
+        let loaded = load_fixture_bundle(&path).unwrap();
+        assert_eq!(loaded.artifacts[0].raw.inline_text.as_deref(), Some(r#"<div class="region">US only</div>"#));
+        let detail_bundle = loaded.artifact_bundle(&loaded.artifacts[0]);
+        let drafts = bundle_to_drafts(&loaded);
+        let geo = drafts[0].geo_constraints.evidence.as_ref().unwrap();
+        assert_eq!(geo.source_url, detail_url);
+        assert_eq!(geo.raw_artifact_id, deterministic_raw_artifact_id_for_bundle(&detail_bundle));
+        let title = drafts[0].title.evidence.as_ref().unwrap();
+        assert_eq!(title.raw_artifact_id, deterministic_raw_artifact_id_for_bundle(&loaded));
+
+        bundle["artifacts"][0]["sha256"] = serde_json::json!("00");
+        let duplicate = bundle["artifacts"][0].clone();
+        bundle["artifacts"].as_array_mut().unwrap().push(duplicate);
+        bundle["parsed_records"][0]["description"]["artifact_url"] = serde_json::json!("https://example.test/missing");
+        fs::write(&path, bundle.to_string()).unwrap();
+        let issues = validate_fixture_bundle(&path).unwrap();
+        let fields = issues.iter().map(|issue| issue.field.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            ["artifacts[0].sha256", "artifacts[1].url", "artifacts[1].sha256", "parsed_records[0].description"],
+            "{issues:?}"
+        );
+    }
+
+    #[test]
+    fn snapshot_update_mode_regenerates_stale_snapshots() {
+        let root = tempfile::tempdir().unwrap();
+        let path = root.path().join("snapshot.json");
+        let adapter = clickworker_adapter();
+        let drafts = adapter.parse_listing(&load_fixture_bundle(fixture_bundle_path("clickworker")).unwrap()).unwrap();
+        let actual = golden_drafts(&drafts, adapter.crawlability());
+
+        assert!(reconcile_golden_snapshot(&path, &actual, false).is_err(), "missing snapshots fail without update mode");
+        assert_eq!(reconcile_golden_snapshot(&path, &actual, true).unwrap(), SnapshotCheck::Updated);
+        assert_eq!(reconcile_golden_snapshot(&path, &actual, false).unwrap(), SnapshotCheck::Matched);
+        assert_eq!(fs::read_to_string(&path).unwrap(), fs::read_to_string(expected_snapshot_path("clickworker")).unwrap());
+
+        let mut changed = actual.clone();
+        changed[0].pay_rate_max = Some(20.0);
+        let err = format!("{:#}", reconcile_golden_snapshot(&path, &changed, false).unwrap_err());
+        assert!(err.contains("UPDATE_SNAPSHOTS=1"), "{err}");
+        assert_eq!(reconcile_golden_snapshot(&path, &changed, true).unwrap(), SnapshotCheck::Updated);
+        assert!(fs::read_to_string(&path).unwrap().contains("\"pay_rate_max\":20.0"));
+    }
+
+    #[test]
+    fn manual_csv_rows_become_drafts_with_row_evidence() {
+        let root = tempfile::tempdir().unwrap();
+        let csv_path = ManualCsvAdapter::csv_path(root.path(), "gig-sheet");
+        fs::create_dir_all(csv_path.parent().unwrap()).unwrap();
+        fs::write(
+            &csv_path,
+            "Title,Pay,Apply_URL,Geo,Notes\n\
+             Audio Transcriber,\"$15-$20/hr, USD\",https://example.com/apply/1,\"US, CA\",ignored\n\
+             ,$5 per task,https://example.com/apply/2,,missing title\n\
+             Image Tagger,$0.10 per task,https://example.com/apply/3,Global,\n",
+        )
+        .unwrap();
+
+        let adapter = resolve_adapter(root.path(), "gig-sheet", Crawlability::ManualOnly, None).unwrap().unwrap();
+        assert_eq!(adapter.extractor_version(), "gig-sheet-csv-v1");
+        let bundle = load_manual_fixture_bundle(&csv_path).unwrap();
+        assert_eq!(bundle.source_id, "gig-sheet");
+        assert_eq!(bundle.raw_artifact.content_type, "text/csv");
+        let drafts = adapter.parse_listing(&bundle).unwrap();
+        assert_eq!(drafts.len(), 2, "the row without a title is skipped");
+
+        let first = &drafts[0];
+        assert_eq!(first.title.value.as_deref(), Some("Audio Transcriber"));
+        assert_eq!(first.pay_model.value.as_deref(), Some("hourly"));
+        assert_eq!((first.pay_rate_min.value, first.pay_rate_max.value), (Some(15.0), Some(20.0)));
+        assert_eq!(first.geo_constraints.value.as_deref(), Some("US, CA"));
+        let evidence = first.apply_url.evidence.as_ref().unwrap();
+        assert_eq!(evidence.selector_or_pointer, "row=2;column=apply_url");
+        assert_eq!(evidence.source_url, "manual://gig-sheet/listings.csv");
+        assert_eq!(drafts[1].title.evidence.as_ref().unwrap().selector_or_pointer, "row=4;column=title");
+        assert_eq!(evidence_coverage_percent(&drafts), 100.0);
+        assert!(validate_fixture_bundle(&csv_path).unwrap().is_empty());
+
+        fs::write(&csv_path, "name,pay\nAudio Transcriber,$15/hr\n").unwrap();
+        let bundle = load_manual_fixture_bundle(&csv_path).unwrap();
+        assert!(adapter.parse_listing(&bundle).unwrap_err().to_string().contains("`title` column"));
+        assert_eq!(validate_fixture_bundle(&csv_path).unwrap()[0].field, "csv");
+    }
+
+    #[test]
+    fn registry_collects_compiled_in_and_runtime_adapters() {
+        let compiled = AdapterRegistry::compiled_in();
+        assert_eq!(
+            compiled.source_ids(),
+            [
+                "appen-crowdgen",
+                "clickworker",
+                "oneforma-jobs",
+                "prolific",
+                "sample-source",
+                "telus-ai-community",
+                "upwork",
+                "weworkremotely"
+            ]
+        );
+        assert_eq!(compiled.build("sample-source").unwrap().crawlability(), Crawlability::PublicHtml);
+        assert!(adapter_for_source("runtime-source").is_none());
+
+        register_adapter("runtime-source", || Box::new(html_title_link_adapter("runtime-source", "runtime-source-v1")));
+        assert_eq!(adapter_for_source("runtime-source").unwrap().source_id(), "runtime-source");
+        assert!(registered_source_ids().contains(&"runtime-source".to_string()));
+
+        let root = tempfile::tempdir().unwrap();
+        let mod_rs = root.path().join("mod.rs");
+        append_generated_module_if_missing(&mod_rs, "9-to-5-gigs").unwrap();
+        append_generated_module_if_missing(&mod_rs, "9-to-5-gigs").unwrap();
+        let text = fs::read_to_string(&mod_rs).unwrap();
+        assert_eq!(text.matches("pub mod source_9_to_5_gigs;").count(), 1);
+        assert!(text.contains("#[path = \"9-to-5-gigs.rs\"]"));
+    }
+
+    #[test]
+    fn configurable_adapter_parses_items_from_selectors_yaml() {
+        let root = tempfile::tempdir().unwrap();
+        assert!(resolve_adapter(root.path(), "acme-gigs", Crawlability::PublicHtml, None).unwrap().is_none());
+        let path = ConfigurableHtmlAdapter::selectors_path(root.path(), "acme-gigs");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(
+            &path,
+            "item: li.gig\ntitle: h2\ndescription: p.summary\npay: .pay\napply_url: a.apply\n",
+        )
+        .unwrap();
+        let adapter = resolve_adapter(root.path(), "acme-gigs", Crawlability::PublicHtml, None).unwrap().unwrap();
+        assert_eq!(adapter.source_id(), "acme-gigs");
+
+        let page = FetchedPage {
+            url: "https://acme.example/gigs".to_string(),
+            content_type: "text/html".to_string(),
+            body: br#"<ul>
+                <li class="gig"><h2>Audio Transcriber</h2><p class="summary">Transcribe short clips.</p>
+                    <span class="pay">$15-$20/hr USD</span><a class="apply" href="/gigs/1/apply">Apply</a></li>
+                <li class="gig"><p class="summary">No title, skipped.</p></li>
+                <li class="gig"><h2>Survey Tester</h2></li>
+            </ul>"#
+                .to_vec(),
+            fetched_at: Utc::now(),
+            not_modified: false,
+        };
+        let bundle = detail_page_bundle("acme-gigs", adapter.crawlability(), "acme-gigs-v1", &page);
         let drafts = adapter.parse_listing(&bundle).unwrap();
+        assert_eq!(drafts.len(), 2);
         assert_all_populated_fields_have_evidence(&drafts);
-        let actual = drafts_to_golden(&drafts, adapter.crawlability());
-        let expected = read_snapshot(&expected_snapshot_path("prolific"));
-        assert_eq!(actual, expected);
+
+        let first = &drafts[0];
+        assert_eq!(first.title.value.as_deref(), Some("Audio Transcriber"));
+        assert_eq!(first.description.value.as_deref(), Some("Transcribe short clips."));
+        assert_eq!(first.pay_rate_min.value, Some(15.0));
+        assert_eq!(first.currency.value.as_deref(), Some("USD"));
+        assert_eq!(first.apply_url.value.as_deref(), Some("https://acme.example/gigs/1/apply"));
+        let evidence = first.apply_url.evidence.as_ref().unwrap();
+        assert_eq!(evidence.selector_or_pointer, "li.gig a.apply");
+        assert_eq!(evidence.source_url, page.url);
+        assert_eq!(drafts[1].title.value.as_deref(), Some("Survey Tester"));
+        assert!(drafts[1].pay_model.value.is_none());
+
+        fs::write(&path, "title: \"h2[\"\n").unwrap();
+        assert!(resolve_adapter(root.path(), "acme-gigs", Crawlability::PublicHtml, None).is_err());
+    }
+
+    #[test]
+    fn meta_tags_fill_fields_when_selectors_miss() {
+        let page = FetchedPage {
+            url: "https://www.clickworker.com/jobs/redesigned".to_string(),
+            content_type: "text/html".to_string(),
+            body: br#"<html><head><title>Fallback Title</title>
+                <meta property="og:title" content="Redesigned Data Contributor">
+                <meta name="description" content="Label data from home.">
+                <link rel="canonical" href="/jobs/42"></head>
+                <body><div class="hero">Redesigned Data Contributor</div></body></html>"#
+                .to_vec(),
+            fetched_at: Utc::now(),
+            not_modified: false,
+        };
+        let adapter = clickworker_adapter();
+        let bundle = detail_page_bundle("clickworker", adapter.crawlability(), "clickworker-v1", &page);
+        let draft = adapter.parse_detail(&bundle).unwrap().remove(0);
+        assert_all_populated_fields_have_evidence(std::slice::from_ref(&draft));
+        assert_eq!(draft.title.value.as_deref(), Some("Redesigned Data Contributor"));
+        assert_eq!(draft.description.value.as_deref(), Some("Label data from home."));
+        assert_eq!(draft.apply_url.value.as_deref(), Some("https://www.clickworker.com/jobs/42"));
+        let evidence = draft.title.evidence.as_ref().unwrap();
+        assert_eq!(evidence.selector_or_pointer, r#"meta[property="og:title"]"#);
+        assert_eq!(evidence.snippet, "Redesigned Data Contributor");
+
+        let selectors = HtmlSelectors {
+            item: Some("li.gig".to_string()),
+            title: "h2".to_string(),
+            description: None,
+            pay: None,
+            apply_url: None,
+            extractor_version: None,
+        };
+        let configurable = ConfigurableHtmlAdapter::new("clickworker", selectors).unwrap();
+        let drafts = configurable.parse_listing(&bundle).unwrap();
+        assert_eq!(drafts.len(), 1);
+        assert_eq!(drafts[0].title.value.as_deref(), Some("Redesigned Data Contributor"));
+        assert_eq!(drafts[0].description.evidence.as_ref().unwrap().selector_or_pointer, r#"meta[name="description"]"#);
     }
 
     #[test]
@@ -0,0 +1,436 @@
+//! Hand-entered listings: `manual/<source_id>/entries.json` holds any number of [`ManualEntry`]
+//! records, checked field by field before anything is parsed into drafts.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rhof_core::{Field, OpportunityDraft};
+use rhof_storage::{ArtifactStore, HttpFetcher};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    set_from_raw, AdapterContext, AdapterError, Crawlability, DetailTarget, FetchedPage, FixtureBundle,
+    FixtureBundleIssue, FixtureRawArtifact, ListingTarget, SourceAdapter, FIXTURE_BUNDLE_SCHEMA_VERSION, PAY_UNITS,
+};
+
+/// File under `manual/<source_id>/` read by [`ManualEntryAdapter`].
+pub const MANUAL_ENTRIES_FILE: &str = "entries.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ManualPayModel {
+    Hourly,
+    Fixed,
+    TaskBased,
+}
+
+impl ManualPayModel {
+    /// The `pay_model` value parsed pay text gets for the same model.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Hourly => "hourly",
+            Self::Fixed => "fixed",
+            Self::TaskBased => "task-based",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ManualCommitment {
+    OneOff,
+    Ongoing,
+}
+
+impl ManualCommitment {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::OneOff => "one_off",
+            Self::Ongoing => "ongoing",
+        }
+    }
+}
+
+/// One hand-entered opportunity. Only `title` is required; see [`ManualEntry::validate`] for the
+/// rules the other fields follow.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManualEntry {
+    pub title: String,
+    /// The listing's id at the source; becomes the draft's external id. Unique within a file.
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub apply_url: Option<String>,
+    #[serde(default)]
+    pub detail_url: Option<String>,
+    #[serde(default)]
+    pub pay_model: Option<ManualPayModel>,
+    /// `hour`, `minute`, `word`, `task`, `study`, `week` or `month`.
+    #[serde(default)]
+    pub pay_unit: Option<String>,
+    #[serde(default)]
+    pub pay_rate_min: Option<f64>,
+    #[serde(default)]
+    pub pay_rate_max: Option<f64>,
+    /// Three-letter ISO 4217 code.
+    #[serde(default)]
+    pub currency: Option<String>,
+    #[serde(default)]
+    pub min_hours_per_week: Option<f64>,
+    #[serde(default)]
+    pub verification_requirements: Option<String>,
+    #[serde(default)]
+    pub geo_constraints: Option<String>,
+    #[serde(default)]
+    pub one_off_vs_ongoing: Option<ManualCommitment>,
+    #[serde(default)]
+    pub payment_methods: Vec<String>,
+    #[serde(default)]
+    pub requirements: Vec<String>,
+}
+
+impl ManualEntry {
+    /// `(field, message)` for every rule the entry breaks.
+    pub fn validate(&self) -> Vec<(&'static str, String)> {
+        let mut issues = Vec::new();
+        if self.title.trim().is_empty() {
+            issues.push(("title", "is required".to_string()));
+        }
+        if self.id.as_deref().is_some_and(|id| id.trim().is_empty()) {
+            issues.push(("id", "must not be blank; leave it out instead".to_string()));
+        }
+        for (field, url) in [("apply_url", &self.apply_url), ("detail_url", &self.detail_url)] {
+            let Some(url) = url else {
+                continue;
+            };
+            match url::Url::parse(url) {
+                Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.host_str().is_some() => {}
+                _ => issues.push((field, format!("`{url}` must be an absolute http(s) URL"))),
+            }
+        }
+        if let Some(unit) = &self.pay_unit {
+            if !PAY_UNITS.iter().any(|(known, _)| known == unit) {
+                let known = PAY_UNITS.iter().map(|(known, _)| *known).collect::<Vec<_>>();
+                issues.push(("pay_unit", format!("`{unit}` is not one of {}", known.join(", "))));
+            }
+        }
+        for (field, rate) in [("pay_rate_min", self.pay_rate_min), ("pay_rate_max", self.pay_rate_max)] {
+            if rate.is_some_and(|rate| !rate.is_finite() || rate < 0.0) {
+                issues.push((field, "must be a non-negative number".to_string()));
+            }
+        }
+        if let (Some(min), Some(max)) = (self.pay_rate_min, self.pay_rate_max) {
+            if min > max {
+                issues.push(("pay_rate_max", format!("{max} is below pay_rate_min {min}")));
+            }
+        }
+        if let Some(currency) = &self.currency {
+            if currency.len() != 3 || !currency.bytes().all(|b| b.is_ascii_uppercase()) {
+                issues.push(("currency", format!("`{currency}` must be a three-letter ISO 4217 code such as USD")));
+            }
+        }
+        if self.min_hours_per_week.is_some_and(|hours| !(0.0..=168.0).contains(&hours)) {
+            issues.push(("min_hours_per_week", "must be within 0-168".to_string()));
+        }
+        for (field, values) in [("payment_methods", &self.payment_methods), ("requirements", &self.requirements)] {
+            if values.iter().any(|value| value.trim().is_empty()) {
+                issues.push((field, "must not contain blank entries".to_string()));
+            }
+        }
+        issues
+    }
+}
+
+/// The layout of [`MANUAL_ENTRIES_FILE`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManualEntriesFile {
+    pub entries: Vec<ManualEntry>,
+}
+
+/// Every problem in a manual entries file, each naming the entry and field (`entries[2].currency`).
+/// Syntax errors, unknown keys and unknown enum values come back as one `entries` issue with the
+/// line and column serde stopped at. Errors only when the file cannot be read.
+pub fn validate_manual_entries(path: impl AsRef<Path>) -> Result<Vec<FixtureBundleIssue>> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    Ok(parse_manual_entries(&text).err().unwrap_or_default())
+}
+
+fn parse_manual_entries(text: &str) -> std::result::Result<Vec<ManualEntry>, Vec<FixtureBundleIssue>> {
+    let file: ManualEntriesFile = serde_json::from_str(text)
+        .map_err(|err| vec![FixtureBundleIssue { field: "entries".to_string(), message: err.to_string() }])?;
+    let mut issues = Vec::new();
+    if file.entries.is_empty() {
+        issues.push(FixtureBundleIssue { field: "entries".to_string(), message: "must contain at least one entry".to_string() });
+    }
+    let mut ids = HashSet::new();
+    for (index, entry) in file.entries.iter().enumerate() {
+        let mut push = |field: &str, message: String| {
+            issues.push(FixtureBundleIssue { field: format!("entries[{index}].{field}"), message })
+        };
+        for (field, message) in entry.validate() {
+            push(field, message);
+        }
+        if let Some(id) = entry.id.as_deref().filter(|id| !id.trim().is_empty()) {
+            if !ids.insert(id) {
+                push("id", format!("`{id}` is already used by an earlier entry"));
+            }
+        }
+    }
+    if issues.is_empty() {
+        Ok(file.entries)
+    } else {
+        Err(issues)
+    }
+}
+
+/// Wrap a valid `entries.json` in a bundle, like [`load_manual_csv_bundle`](crate::load_manual_csv_bundle):
+/// the file is the raw artifact and [`ManualEntryAdapter`] fills the records. An invalid file is an
+/// error listing every issue.
+pub fn load_manual_entries_bundle(path: impl AsRef<Path>) -> Result<FixtureBundle> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    if let Err(issues) = parse_manual_entries(&text) {
+        let issues = issues.iter().map(ToString::to_string).collect::<Vec<_>>();
+        anyhow::bail!("{} has invalid entries:\n  {}", path.display(), issues.join("\n  "));
+    }
+    let source_id = path
+        .parent()
+        .and_then(Path::file_name)
+        .and_then(|name| name.to_str())
+        .with_context(|| format!("{} is not under manual/<source_id>/", path.display()))?
+        .to_string();
+    let fetched_at = fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .map(DateTime::<Utc>::from)
+        .with_context(|| format!("reading modification time of {}", path.display()))?;
+    let sha256 = ArtifactStore::sha256_hex(text.as_bytes());
+    Ok(FixtureBundle {
+        schema_version: FIXTURE_BUNDLE_SCHEMA_VERSION,
+        fixture_id: format!("entries-{}", &sha256[..12]),
+        captured_from_url: format!("manual://{source_id}/{MANUAL_ENTRIES_FILE}"),
+        extractor_version: ManualEntryAdapter::new(source_id.as_str()).extractor_version,
+        source_id,
+        crawlability: Crawlability::ManualOnly,
+        fetched_at,
+        raw_artifact: FixtureRawArtifact {
+            content_type: "application/json".to_string(),
+            path: Some(MANUAL_ENTRIES_FILE.to_string()),
+            inline_text: Some(text),
+            sha256: Some(sha256),
+        },
+        artifacts: Vec::new(),
+        parsed_records: Vec::new(),
+        evidence_coverage_percent: 100.0,
+        notes: Some("manual entries".to_string()),
+    })
+}
+
+/// Adapter for sources whose listings are typed in by hand as [`ManualEntry`] records: one draft
+/// per entry, with evidence pointing at each value's JSON pointer (`/entries/2/title`).
+#[derive(Debug, Clone)]
+pub struct ManualEntryAdapter {
+    source_id: String,
+    extractor_version: String,
+}
+
+impl ManualEntryAdapter {
+    pub fn new(source_id: impl Into<String>) -> Self {
+        let source_id = source_id.into();
+        let extractor_version = format!("{source_id}-entries-v1");
+        Self { source_id, extractor_version }
+    }
+
+    pub fn entries_path(workspace_root: &Path, source_id: &str) -> PathBuf {
+        workspace_root.join("manual").join(source_id).join(MANUAL_ENTRIES_FILE)
+    }
+
+    /// The adapter for a source with an `entries.json`, or `None` when it has none.
+    pub fn load(workspace_root: &Path, source_id: &str) -> Option<Self> {
+        Self::entries_path(workspace_root, source_id).is_file().then(|| Self::new(source_id))
+    }
+
+    fn entry_draft(&self, bundle: &FixtureBundle, index: usize, entry: &ManualEntry) -> OpportunityDraft {
+        let mut draft = OpportunityDraft {
+            source_id: self.source_id.clone(),
+            listing_url: Some(bundle.captured_from_url.clone()),
+            detail_url: entry.detail_url.clone(),
+            external_id: entry.id.clone(),
+            language: None,
+            fetched_at: bundle.fetched_at,
+            extractor_version: bundle.extractor_version.clone(),
+            title: Field::empty(),
+            description: Field::empty(),
+            pay_model: Field::empty(),
+            pay_unit: Field::empty(),
+            pay_rate_min: Field::empty(),
+            pay_rate_max: Field::empty(),
+            currency: Field::empty(),
+            min_hours_per_week: Field::empty(),
+            verification_requirements: Field::empty(),
+            geo_constraints: Field::empty(),
+            one_off_vs_ongoing: Field::empty(),
+            payment_methods: Field::empty(),
+            apply_url: Field::empty(),
+            requirements: Field::empty(),
+        };
+        let pointer = |field: &str| format!("/entries/{index}/{field}");
+        let text_fields = [
+            (&mut draft.title, "title", Some(entry.title.trim().to_string())),
+            (&mut draft.description, "description", entry.description.clone()),
+            (&mut draft.pay_model, "pay_model", entry.pay_model.map(|model| model.as_str().to_string())),
+            (&mut draft.pay_unit, "pay_unit", entry.pay_unit.clone()),
+            (&mut draft.currency, "currency", entry.currency.clone()),
+            (&mut draft.verification_requirements, "verification_requirements", entry.verification_requirements.clone()),
+            (&mut draft.geo_constraints, "geo_constraints", entry.geo_constraints.clone()),
+            (&mut draft.one_off_vs_ongoing, "one_off_vs_ongoing", entry.one_off_vs_ongoing.map(|c| c.as_str().to_string())),
+            (&mut draft.apply_url, "apply_url", entry.apply_url.clone()),
+        ];
+        for (field, name, value) in text_fields {
+            let snippet = value.clone().unwrap_or_default();
+            set_from_raw(field, value, bundle, &pointer(name), &snippet);
+        }
+        let number_fields = [
+            (&mut draft.pay_rate_min, "pay_rate_min", entry.pay_rate_min),
+            (&mut draft.pay_rate_max, "pay_rate_max", entry.pay_rate_max),
+            (&mut draft.min_hours_per_week, "min_hours_per_week", entry.min_hours_per_week),
+        ];
+        for (field, name, value) in number_fields {
+            let snippet = value.map(|value| value.to_string()).unwrap_or_default();
+            set_from_raw(field, value, bundle, &pointer(name), &snippet);
+        }
+        let list_fields = [
+            (&mut draft.payment_methods, "payment_methods", &entry.payment_methods),
+            (&mut draft.requirements, "requirements", &entry.requirements),
+        ];
+        for (field, name, values) in list_fields {
+            let value = (!values.is_empty()).then(|| values.clone());
+            set_from_raw(field, value, bundle, &pointer(name), &values.join(", "));
+        }
+        draft
+    }
+}
+
+#[async_trait]
+impl SourceAdapter for ManualEntryAdapter {
+    fn source_id(&self) -> &str {
+        &self.source_id
+    }
+
+    fn crawlability(&self) -> Crawlability {
+        Crawlability::ManualOnly
+    }
+
+    fn extractor_version(&self) -> &str {
+        &self.extractor_version
+    }
+
+    async fn fetch_listing(
+        &self,
+        _http: &HttpFetcher,
+        _ctx: &AdapterContext,
+        _targets: &[ListingTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        Ok(Vec::new())
+    }
+
+    /// One draft per entry. A file that breaks the [`ManualEntry`] schema is a schema mismatch
+    /// naming the first issue; `rhof-cli validate-sources` lists them all.
+    fn parse_listing(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        if bundle.source_id != self.source_id {
+            return Err(AdapterError::source_mismatch(bundle, &self.source_id));
+        }
+        let Some(text) = bundle.raw_artifact.inline_text.as_deref() else {
+            return Err(AdapterError::ParseFailed(format!("{MANUAL_ENTRIES_FILE} was not loaded")));
+        };
+        let entries = parse_manual_entries(text).map_err(|issues| {
+            AdapterError::SchemaMismatch(format!("{MANUAL_ENTRIES_FILE}: {} ({} issues)", issues[0], issues.len()))
+        })?;
+        Ok(entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| self.entry_draft(bundle, index, entry))
+            .collect())
+    }
+
+    async fn fetch_detail(
+        &self,
+        _http: &HttpFetcher,
+        _ctx: &AdapterContext,
+        _targets: &[DetailTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        Ok(Vec::new())
+    }
+
+    fn parse_detail(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        self.parse_listing(bundle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_are_validated_field_by_field_and_parse_with_pointer_evidence() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join("manual/hand-picked");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(MANUAL_ENTRIES_FILE);
+        let entries = serde_json::json!({ "entries": [
+            { "title": "Voice Recording", "id": "v-1", "pay_model": "task-based", "pay_unit": "task",
+              "pay_rate_min": 5, "pay_rate_max": 8, "currency": "EUR", "one_off_vs_ongoing": "one_off",
+              "apply_url": "https://example.test/voice", "requirements": ["Quiet room", "Headset"] },
+            { "title": "Search Evaluator", "pay_model": "hourly", "geo_constraints": "US only" },
+        ]});
+        fs::write(&path, entries.to_string()).unwrap();
+        assert!(validate_manual_entries(&path).unwrap().is_empty());
+
+        let bundle = load_manual_entries_bundle(&path).unwrap();
+        assert_eq!(bundle.source_id, "hand-picked");
+        let adapter = ManualEntryAdapter::new("hand-picked");
+        let drafts = adapter.parse_listing(&bundle).unwrap();
+        assert_eq!(drafts.len(), 2);
+        assert_eq!(drafts[0].external_id.as_deref(), Some("v-1"));
+        assert_eq!(drafts[0].pay_model.value.as_deref(), Some("task-based"));
+        assert_eq!(drafts[0].requirements.value.as_ref().unwrap().len(), 2);
+        let evidence = drafts[1].geo_constraints.evidence.as_ref().unwrap();
+        assert_eq!(evidence.selector_or_pointer, "/entries/1/geo_constraints");
+        assert_eq!(evidence.source_url, "manual://hand-picked/entries.json");
+
+        let broken = serde_json::json!({ "entries": [
+            { "title": " ", "apply_url": "example.test/apply", "currency": "usd", "id": "x" },
+            { "title": "Rater", "pay_rate_min": 20, "pay_rate_max": 10, "pay_unit": "fortnight", "id": "x" },
+        ]});
+        fs::write(&path, broken.to_string()).unwrap();
+        let issues = validate_manual_entries(&path).unwrap();
+        let fields = issues.iter().map(|issue| issue.field.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            [
+                "entries[0].title",
+                "entries[0].apply_url",
+                "entries[0].currency",
+                "entries[1].pay_unit",
+                "entries[1].pay_rate_max",
+                "entries[1].id"
+            ],
+            "{issues:?}"
+        );
+        let err = format!("{:#}", load_manual_entries_bundle(&path).unwrap_err());
+        assert!(err.contains("entries[1].id: `x` is already used by an earlier entry"), "{err}");
+
+        fs::write(&path, r#"{"entries": [{"title": "Rater", "pay_model": "weekly"}]}"#).unwrap();
+        let issues = validate_manual_entries(&path).unwrap();
+        assert!(issues[0].message.contains("unknown variant `weekly`"), "{issues:?}");
+        assert!(issues[0].message.contains("line 1 column"), "{issues:?}");
+    }
+}
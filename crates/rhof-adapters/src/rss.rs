@@ -0,0 +1,357 @@
+//! RSS 2.0 / Atom feeds: a generic feed adapter base plus the flavors of the gig feeds we follow
+//! (Upwork saved searches, We Work Remotely), which differ only in where titles, pay and location
+//! hide inside an item.
+
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use rhof_core::{Field, OpportunityDraft};
+use rhof_storage::HttpFetcher;
+use scraper::Html;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bundle_to_drafts, detect_currency, parse_pay_fields, set_from_raw, text_or_none, AdapterContext, AdapterError,
+    Crawlability, DetailTarget, FetchedPage, FixtureBundle, ListingTarget, SourceAdapter,
+};
+
+/// One `<item>` (RSS) or `<entry>` (Atom) of a feed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeedItem {
+    /// `item` or `entry`.
+    pub element: String,
+    /// 1-based position among the feed's items.
+    pub position: usize,
+    /// Text of the first element of each (qualified, lower-cased) name inside the item, e.g.
+    /// `title`, `description`, `content:encoded`; Atom's `<link href>` is stored as `link`.
+    pub fields: BTreeMap<String, String>,
+}
+
+impl FeedItem {
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.fields.get(name).map(String::as_str)
+    }
+
+    /// Evidence path of one of the item's fields, e.g. `item[3]/title`.
+    pub fn path(&self, name: &str) -> String {
+        format!("{}[{}]/{name}", self.element, self.position)
+    }
+}
+
+/// Every item of an RSS 2.0 or Atom document, in feed order.
+pub fn parse_feed_items(xml: &str) -> Result<Vec<FeedItem>, AdapterError> {
+    let mut reader = Reader::from_str(xml);
+    let mut items = Vec::new();
+    let mut current: Option<FeedItem> = None;
+    let mut open = Vec::<String>::new();
+    let mut text = String::new();
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|err| AdapterError::ParseFailed(format!("parsing feed XML: {err}")))?;
+        match event {
+            Event::Start(start) => {
+                let name = element_name(&start);
+                match &mut current {
+                    None if name == "item" || name == "entry" => {
+                        current = Some(FeedItem { element: name, position: items.len() + 1, ..FeedItem::default() });
+                    }
+                    None => {}
+                    Some(item) => {
+                        if name == "link" {
+                            record_atom_link(item, &start);
+                        }
+                        open.push(name);
+                        text.clear();
+                    }
+                }
+            }
+            Event::Empty(empty) => {
+                if let Some(item) = &mut current {
+                    if element_name(&empty) == "link" {
+                        record_atom_link(item, &empty);
+                    }
+                }
+            }
+            Event::Text(chunk) if !open.is_empty() => {
+                let chunk = chunk
+                    .unescape()
+                    .map_err(|err| AdapterError::ParseFailed(format!("unescaping feed text: {err}")))?;
+                text.push_str(&chunk);
+            }
+            Event::CData(chunk) if !open.is_empty() => text.push_str(&String::from_utf8_lossy(&chunk)),
+            Event::End(_) => {
+                let Some(item) = &mut current else {
+                    continue;
+                };
+                match open.pop() {
+                    Some(name) => {
+                        if let Some(value) = text_or_none(std::mem::take(&mut text)) {
+                            item.fields.entry(name).or_insert(value);
+                        }
+                    }
+                    None => items.extend(current.take()),
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(items)
+}
+
+fn element_name(start: &BytesStart<'_>) -> String {
+    String::from_utf8_lossy(start.name().as_ref()).to_ascii_lowercase()
+}
+
+/// Atom links carry the URL in `href`; the first `alternate` (or untyped) one is the item's page.
+fn record_atom_link(item: &mut FeedItem, link: &BytesStart<'_>) {
+    let attr = |key: &[u8]| {
+        link.try_get_attribute(key)
+            .ok()
+            .flatten()
+            .and_then(|attr| attr.unescape_value().ok().map(|value| value.into_owned()))
+    };
+    let rel = attr(b"rel");
+    if let Some(href) = attr(b"href").filter(|_| rel.as_deref().is_none_or(|rel| rel == "alternate")) {
+        item.fields.entry("link".to_string()).or_insert(href);
+    }
+}
+
+/// Plain text of an HTML fragment, one line per `<br>`, paragraph or list item.
+fn feed_html_lines(html: &str) -> Vec<String> {
+    let mut marked = html.to_string();
+    for tag in ["<br>", "<br/>", "<br />", "</p>", "</li>", "</div>"] {
+        marked = marked.replace(tag, "\n").replace(&tag.to_ascii_uppercase(), "\n");
+    }
+    let text = Html::parse_fragment(&marked).root_element().text().collect::<String>();
+    text.lines()
+        .filter_map(|line| text_or_none(line.split_whitespace().collect::<Vec<_>>().join(" ")))
+        .collect()
+}
+
+/// The value of a `Label: value` line of an item description.
+fn labeled_value<'a>(lines: &'a [String], label: &str) -> Option<(&'a str, &'a str)> {
+    lines.iter().find_map(|line| {
+        let rest = line.strip_prefix(label)?.trim_start().strip_prefix(':')?.trim();
+        (!rest.is_empty()).then_some((rest, line.as_str()))
+    })
+}
+
+/// The first description line that states an amount of money.
+fn pay_line(lines: &[String]) -> Option<&str> {
+    lines
+        .iter()
+        .map(String::as_str)
+        .find(|line| detect_currency(line).is_some() && line.chars().any(|c| c.is_ascii_digit()))
+}
+
+/// Where a feed's items keep the fields the generic mapping cannot find.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedFlavor {
+    /// Title, link, guid and description only; pay from the first description line naming an amount.
+    Generic,
+    /// Upwork saved-search feeds: `<b>Hourly Range</b>` / `<b>Budget</b>`, `<b>Country</b>` and
+    /// `<b>Skills</b>` lines in the description, and a ` - Upwork` title suffix.
+    Upwork,
+    /// We Work Remotely: `Company: Role` titles and `<region>` / `<type>` elements.
+    WeWorkRemotely,
+}
+
+/// Adapter base for RSS/Atom sources: fetches each listing URL as one feed page and maps every
+/// item to a draft according to its [`FeedFlavor`]. Evidence points at the item element the
+/// value came from (`item[2]/description`).
+#[derive(Debug, Clone)]
+pub struct RssFeedAdapter {
+    source_id: String,
+    extractor_version: String,
+    flavor: FeedFlavor,
+}
+
+impl RssFeedAdapter {
+    pub fn new(source_id: impl Into<String>, extractor_version: impl Into<String>, flavor: FeedFlavor) -> Self {
+        Self { source_id: source_id.into(), extractor_version: extractor_version.into(), flavor }
+    }
+
+    fn empty_draft(&self, bundle: &FixtureBundle) -> OpportunityDraft {
+        OpportunityDraft {
+            source_id: self.source_id.clone(),
+            listing_url: Some(bundle.captured_from_url.clone()),
+            detail_url: None,
+            external_id: None,
+            language: None,
+            fetched_at: bundle.fetched_at,
+            extractor_version: bundle.extractor_version.clone(),
+            title: Field::empty(),
+            description: Field::empty(),
+            pay_model: Field::empty(),
+            pay_unit: Field::empty(),
+            pay_rate_min: Field::empty(),
+            pay_rate_max: Field::empty(),
+            currency: Field::empty(),
+            min_hours_per_week: Field::empty(),
+            verification_requirements: Field::empty(),
+            geo_constraints: Field::empty(),
+            one_off_vs_ongoing: Field::empty(),
+            payment_methods: Field::empty(),
+            apply_url: Field::empty(),
+            requirements: Field::empty(),
+        }
+    }
+
+    fn item_draft(&self, bundle: &FixtureBundle, item: &FeedItem) -> OpportunityDraft {
+        let mut draft = self.empty_draft(bundle);
+        let link = item.get("link").map(str::to_string);
+        draft.detail_url = link.clone();
+        draft.external_id = item.get("guid").or(item.get("id")).map(str::to_string);
+
+        if let Some(raw) = item.get("title") {
+            let title = match self.flavor {
+                FeedFlavor::Upwork => raw.trim_end_matches(" - Upwork").to_string(),
+                FeedFlavor::WeWorkRemotely => raw.split_once(':').map_or(raw, |(_, role)| role).trim().to_string(),
+                FeedFlavor::Generic => raw.to_string(),
+            };
+            set_from_raw(&mut draft.title, text_or_none(title), bundle, &item.path("title"), raw);
+        }
+        if let Some(link) = link {
+            set_from_raw(&mut draft.apply_url, Some(link.clone()), bundle, &item.path("link"), &link);
+        }
+
+        let body_field = ["content:encoded", "description", "content", "summary"]
+            .into_iter()
+            .find(|name| item.get(name).is_some());
+        let Some(body_field) = body_field else {
+            return draft;
+        };
+        let body = item.get(body_field).unwrap_or_default();
+        let body_path = item.path(body_field);
+        let lines = feed_html_lines(body);
+        set_from_raw(&mut draft.description, text_or_none(lines.join(" ")), bundle, &body_path, body);
+
+        let pay = match self.flavor {
+            FeedFlavor::Upwork => labeled_value(&lines, "Hourly Range")
+                .map(|(range, line)| (format!("{range} hourly"), line))
+                .or_else(|| labeled_value(&lines, "Budget").map(|(budget, line)| (format!("{budget} fixed"), line))),
+            FeedFlavor::Generic | FeedFlavor::WeWorkRemotely => pay_line(&lines).map(|line| (line.to_string(), line)),
+        };
+        if let Some((pay_text, snippet)) = pay {
+            let parsed = parse_pay_fields(&pay_text);
+            set_from_raw(&mut draft.pay_model, parsed.model, bundle, &body_path, snippet);
+            set_from_raw(&mut draft.pay_unit, parsed.unit, bundle, &body_path, snippet);
+            set_from_raw(&mut draft.pay_rate_min, parsed.rate_min, bundle, &body_path, snippet);
+            set_from_raw(&mut draft.pay_rate_max, parsed.rate_max, bundle, &body_path, snippet);
+            set_from_raw(&mut draft.currency, parsed.currency, bundle, &body_path, snippet);
+        }
+
+        match self.flavor {
+            FeedFlavor::Upwork => {
+                if let Some((country, line)) = labeled_value(&lines, "Country") {
+                    set_from_raw(&mut draft.geo_constraints, Some(country.to_string()), bundle, &body_path, line);
+                }
+                if let Some((skills, line)) = labeled_value(&lines, "Skills") {
+                    let skills = skills.split(',').filter_map(|skill| text_or_none(skill.to_string())).collect::<Vec<_>>();
+                    set_from_raw(&mut draft.requirements, (!skills.is_empty()).then_some(skills), bundle, &body_path, line);
+                }
+            }
+            FeedFlavor::WeWorkRemotely => {
+                for (field, name) in [(&mut draft.geo_constraints, "region"), (&mut draft.one_off_vs_ongoing, "type")] {
+                    if let Some(value) = item.get(name) {
+                        set_from_raw(field, Some(value.to_string()), bundle, &item.path(name), value);
+                    }
+                }
+            }
+            FeedFlavor::Generic => {}
+        }
+        draft
+    }
+}
+
+#[async_trait]
+impl SourceAdapter for RssFeedAdapter {
+    fn source_id(&self) -> &str {
+        &self.source_id
+    }
+
+    fn crawlability(&self) -> Crawlability {
+        Crawlability::Rss
+    }
+
+    fn extractor_version(&self) -> &str {
+        &self.extractor_version
+    }
+
+    /// The RSS `<channel>` or Atom `<feed>` root; an error page served in place of the feed has neither.
+    fn sentinel_selector(&self) -> Option<&str> {
+        Some("channel, feed")
+    }
+
+    /// One page per listing URL; feeds are not paginated.
+    async fn fetch_listing(
+        &self,
+        http: &HttpFetcher,
+        ctx: &AdapterContext,
+        targets: &[ListingTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        let mut pages = Vec::with_capacity(targets.len());
+        for target in targets {
+            let url = &target.url;
+            let response = http
+                .fetch_bytes_with(ctx.run_id, &self.source_id, url, ctx.robots())
+                .await
+                .map_err(|err| AdapterError::fetch_failed(url, err))?;
+            pages.push(FetchedPage::from_response(response, "application/rss+xml"));
+        }
+        Ok(pages)
+    }
+
+    /// One draft per titled item; a bundle without a raw feed falls back to its parsed records.
+    fn parse_listing(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        if bundle.source_id != self.source_id {
+            return Err(AdapterError::source_mismatch(bundle, &self.source_id));
+        }
+        let Some(xml) = bundle.raw_artifact.inline_text.as_deref() else {
+            return Ok(bundle_to_drafts(bundle));
+        };
+        Ok(parse_feed_items(xml)?
+            .iter()
+            .map(|item| self.item_draft(bundle, item))
+            .filter(|draft| draft.title.value.is_some())
+            .collect())
+    }
+
+    async fn fetch_detail(
+        &self,
+        _http: &HttpFetcher,
+        _ctx: &AdapterContext,
+        _targets: &[DetailTarget],
+    ) -> Result<Vec<FetchedPage>, AdapterError> {
+        Ok(Vec::new())
+    }
+
+    fn parse_detail(&self, bundle: &FixtureBundle) -> Result<Vec<OpportunityDraft>, AdapterError> {
+        self.parse_listing(bundle)
+    }
+}
+
+/// A feed source without source-specific fields, e.g. a remote-work board's category feed.
+pub fn rss_feed_adapter(source_id: impl Into<String>) -> RssFeedAdapter {
+    let source_id = source_id.into();
+    let extractor_version = format!("{source_id}-rss-v1");
+    RssFeedAdapter::new(source_id, extractor_version, FeedFlavor::Generic)
+}
+
+/// Upwork saved-search RSS (`upwork.com/ab/feed/jobs/rss?q=...`, one listing URL per search).
+pub fn upwork_rss_adapter() -> RssFeedAdapter {
+    RssFeedAdapter::new("upwork", "upwork-rss-v1", FeedFlavor::Upwork)
+}
+
+/// We Work Remotely category feeds (`weworkremotely.com/categories/<category>.rss`).
+pub fn weworkremotely_adapter() -> RssFeedAdapter {
+    RssFeedAdapter::new("weworkremotely", "weworkremotely-rss-v1", FeedFlavor::WeWorkRemotely)
+}
+
+inventory::submit! { crate::AdapterRegistration { source_id: "upwork", build: || Box::new(upwork_rss_adapter()) } }
+inventory::submit! { crate::AdapterRegistration { source_id: "weworkremotely", build: || Box::new(weworkremotely_adapter()) } }
@@ -11,16 +11,83 @@ struct Cli {
 
 #[derive(Debug, Subcommand)]
 enum Commands {
-    Sync,
+    Sync {
+        /// Only sync the given source_id (repeatable); defaults to every enabled source.
+        #[arg(long = "source", value_name = "SOURCE_ID")]
+        sources: Vec<String>,
+        /// Draw a per-source progress bar on stderr while the run is in flight.
+        #[arg(long)]
+        progress: bool,
+    },
+    /// Continue a sync run that stopped part-way, from its checkpoint under reports/.
+    Resume {
+        run_id: uuid::Uuid,
+    },
+    /// Re-parse stored raw artifacts with the current adapters and version any changed output.
+    Backfill,
+    /// Parse one stored raw artifact with its adapter and print the drafts as JSON.
+    Replay {
+        raw_artifact_id: uuid::Uuid,
+    },
+    /// Delete stored raw artifacts past a retention policy; referenced ones are kept by default.
+    GcArtifacts {
+        /// Delete artifacts stored more than this many days ago.
+        #[arg(long)]
+        max_age_days: Option<i64>,
+        /// Then delete the oldest artifacts until the store fits this many MiB.
+        #[arg(long)]
+        max_total_mb: Option<u64>,
+        /// Also delete artifacts that still have a raw_artifacts row (replay/backfill skip them).
+        #[arg(long)]
+        delete_referenced: bool,
+        /// Only list what would be deleted.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Re-hash stored raw artifacts and list missing or corrupted ones; fails if there are any.
+    VerifyArtifacts {
+        /// Record the result in each affected raw_artifacts row (metadata_json.integrity).
+        #[arg(long)]
+        mark: bool,
+    },
+    /// Browse stored raw artifacts through the artifact catalog, without walking the store.
+    Artifacts {
+        #[command(subcommand)]
+        command: ArtifactCommands,
+    },
     Report {
         #[command(subcommand)]
         command: ReportCommands,
     },
+    Warehouse {
+        #[command(subcommand)]
+        command: WarehouseCommands,
+    },
     NewAdapter {
         source_id: String,
     },
-    Seed,
+    /// Fetch a live page and record it as fixtures/<source_id>/sample (raw body, bundle, snapshot).
+    CaptureFixture {
+        source_id: String,
+        url: String,
+        /// Replace an existing sample bundle.
+        #[arg(long)]
+        force: bool,
+    },
+    Seed {
+        /// Derive the run id from the fixture content so re-seeding unchanged fixtures is idempotent.
+        #[arg(long)]
+        deterministic: bool,
+    },
+    /// Probe each enabled source's first listing page and flag adapters whose sentinel no longer matches.
+    HealthCheck {
+        /// Only check the given source_id (repeatable); defaults to every enabled source.
+        #[arg(long = "source", value_name = "SOURCE_ID")]
+        sources: Vec<String>,
+    },
     Debug,
+    /// Check sources.yaml for duplicate ids, unknown modes, missing adapters/bundles and bad URLs.
+    ValidateSources,
     Migrate,
     Scheduler,
     Serve,
@@ -34,18 +101,159 @@ enum ReportCommands {
     },
 }
 
+#[derive(Debug, Subcommand)]
+enum ArtifactCommands {
+    /// List catalogued captures, oldest first.
+    List {
+        /// Only captures of this source_id.
+        #[arg(long = "source", value_name = "SOURCE_ID")]
+        source: Option<String>,
+        /// Only captures fetched on or after this day (YYYY-MM-DD, UTC).
+        #[arg(long)]
+        since: Option<chrono::NaiveDate>,
+        /// Only captures fetched on or before this day (YYYY-MM-DD, UTC).
+        #[arg(long)]
+        until: Option<chrono::NaiveDate>,
+    },
+    /// Capture counts and sizes per source and extension.
+    Stats,
+    /// Rebuild the catalog from one full walk of the store (artifacts stored before it existed).
+    RebuildCatalog,
+}
+
+#[derive(Debug, Subcommand)]
+enum WarehouseCommands {
+    /// Merge the per-run parquet files of each warehouse partition into one file.
+    Compact {
+        #[arg(long, default_value_t = 2)]
+        min_files: usize,
+    },
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    let _tracing = rhof_sync::init_tracing(&rhof_sync::SyncConfig::load()?)?;
 
-    match cli.command.unwrap_or(Commands::Sync) {
-        Commands::Sync => {
-            let summary = rhof_sync::run_sync_once_from_env().await?;
+    match cli.command.unwrap_or(Commands::Sync { sources: Vec::new(), progress: false }) {
+        Commands::Sync { sources, progress: true } => {
+            let summary = sync_with_progress(sources).await?;
+            print_sync_summary(&summary);
+        }
+        Commands::Sync { sources, progress: false } => {
+            let summary = if sources.is_empty() {
+                rhof_sync::run_sync_once_from_env().await?
+            } else {
+                let source_ids = sources.iter().map(String::as_str).collect::<Vec<_>>();
+                rhof_sync::run_sync_for_sources_from_env(&source_ids).await?
+            };
+            print_sync_summary(&summary);
+        }
+        Commands::Resume { run_id } => {
+            let summary = rhof_sync::resume_sync_from_env(run_id).await?;
+            print_sync_summary(&summary);
+        }
+        Commands::Backfill => {
+            let summary = rhof_sync::backfill_from_env().await?;
             println!(
-                "sync complete: run_id={} sources={} drafts={} reports={}",
-                summary.run_id, summary.enabled_sources, summary.parsed_drafts, summary.reports_dir
+                "backfill complete: run_id={} artifacts={} drafts={} new_versions={}",
+                summary.run_id, summary.reparsed_artifacts, summary.parsed_drafts, summary.persisted_versions
+            );
+            for skipped in &summary.skipped {
+                println!("skipped: {skipped}");
+            }
+        }
+        Commands::Replay { raw_artifact_id } => {
+            let drafts = rhof_sync::replay_artifact(raw_artifact_id).await?;
+            println!("{}", serde_json::to_string_pretty(&drafts)?);
+        }
+        Commands::GcArtifacts { max_age_days, max_total_mb, delete_referenced, dry_run } => {
+            let policy = rhof_storage::RetentionPolicy {
+                max_age: max_age_days.map(chrono::Duration::days),
+                max_total_bytes: max_total_mb.map(|mb| mb * 1024 * 1024),
+                keep_referenced: !delete_referenced,
+            };
+            let report = rhof_sync::gc_artifacts_from_env(&policy, dry_run).await?;
+            for key in &report.deleted {
+                println!("- {key}");
+            }
+            println!(
+                "artifact gc complete: scanned={} deleted={} reclaimed_bytes={} kept_referenced={} dry_run={}",
+                report.scanned,
+                report.deleted.len(),
+                report.reclaimed_bytes,
+                report.kept_referenced,
+                report.dry_run
             );
-            println!("parquet manifest: {}", summary.parquet_manifest);
+        }
+        Commands::VerifyArtifacts { mark } => {
+            let report = rhof_sync::verify_artifacts_from_env(mark).await?;
+            for path in &report.missing {
+                println!("missing: {path}");
+            }
+            for corrupt in &report.corrupted {
+                println!(
+                    "corrupted: {} (expected sha256 {}, found {})",
+                    corrupt.key,
+                    corrupt.expected_hash,
+                    corrupt.actual_hash.as_deref().unwrap_or("unreadable bytes")
+                );
+            }
+            println!(
+                "artifact verification complete: checked={} missing={} corrupted={} marked={}",
+                report.checked,
+                report.missing.len(),
+                report.corrupted.len(),
+                mark
+            );
+            if !report.is_clean() {
+                anyhow::bail!("{} missing and {} corrupted artifacts", report.missing.len(), report.corrupted.len());
+            }
+        }
+        Commands::Artifacts { command } => {
+            let store = rhof_sync::artifact_store_from_env()?;
+            match command {
+                ArtifactCommands::List { source, since, until } => {
+                    let day = |date: chrono::NaiveDate| date.and_time(chrono::NaiveTime::MIN).and_utc();
+                    let since = since.map_or(std::ops::Bound::Unbounded, |date| std::ops::Bound::Included(day(date)));
+                    let until = until.map_or(std::ops::Bound::Unbounded, |date| {
+                        std::ops::Bound::Excluded(day(date + chrono::Duration::days(1)))
+                    });
+                    let entries = store.list_artifacts(source.as_deref(), (since, until)).await?;
+                    for entry in &entries {
+                        println!("{} {} {} {}", entry.fetched_at.to_rfc3339(), entry.source_id, entry.byte_size, entry.key);
+                    }
+                    println!("captures={}", entries.len());
+                }
+                ArtifactCommands::Stats => {
+                    let stats = store.catalog_stats().await?;
+                    for (source_id, source) in &stats.sources {
+                        println!(
+                            "- {source_id}: captures={} bytes={} first={} last={}",
+                            source.captures,
+                            source.total_bytes,
+                            source.first_fetched_at.map(|at| at.to_rfc3339()).unwrap_or_default(),
+                            source.last_fetched_at.map(|at| at.to_rfc3339()).unwrap_or_default()
+                        );
+                    }
+                    let extensions = stats
+                        .extensions
+                        .iter()
+                        .map(|(extension, count)| format!("{extension}={count}"))
+                        .collect::<Vec<_>>();
+                    println!(
+                        "artifact catalog: captures={} artifacts={} bytes={} extensions: {}",
+                        stats.captures,
+                        stats.artifacts,
+                        stats.total_bytes,
+                        extensions.join(" ")
+                    );
+                }
+                ArtifactCommands::RebuildCatalog => {
+                    let captures = store.rebuild_catalog().await?;
+                    println!("artifact catalog rebuilt: captures={captures}");
+                }
+            }
         }
         Commands::Report { command } => match command {
             ReportCommands::Daily { runs } => {
@@ -53,6 +261,19 @@ async fn main() -> Result<()> {
                 println!("{markdown}");
             }
         },
+        Commands::Warehouse { command } => match command {
+            WarehouseCommands::Compact { min_files } => {
+                let config = rhof_sync::SyncConfig::load()?;
+                let Some(warehouse_dir) = config.warehouse_dir else {
+                    anyhow::bail!("RHOF_WAREHOUSE_DIR is not set; there is no warehouse to compact");
+                };
+                let summary = rhof_sync::compact_warehouse(&warehouse_dir, min_files)?;
+                println!(
+                    "warehouse compacted: partitions={} files_removed={} rows={}",
+                    summary.partitions_compacted, summary.files_removed, summary.rows
+                );
+            }
+        },
         Commands::NewAdapter { source_id } => {
             let created = rhof_adapters::generate_adapter_scaffold(".", &source_id)?;
             println!("generated adapter scaffold for `{}`", source_id);
@@ -60,18 +281,60 @@ async fn main() -> Result<()> {
                 println!("- {}", path.display());
             }
         }
-        Commands::Seed => {
-            let summary = rhof_sync::seed_from_fixtures_from_env().await?;
+        Commands::CaptureFixture { source_id, url, force } => {
+            let captured = rhof_sync::capture_fixture(&source_id, &url, force).await?;
+            println!(
+                "capture complete: bundle={} records={} coverage={} sha256={}",
+                captured.bundle_path.display(),
+                captured.parsed_records,
+                captured.evidence_coverage_percent,
+                captured.sha256
+            );
+        }
+        Commands::Seed { deterministic } => {
+            let mut config = rhof_sync::SyncConfig::load()?;
+            config.deterministic_run_ids |= deterministic;
+            let summary = rhof_sync::seed_from_fixtures_with_config(config).await?;
             println!(
                 "seed complete (fixture-derived): run_id={} artifacts={} drafts={} reports={}",
                 summary.run_id, summary.fetched_artifacts, summary.parsed_drafts, summary.reports_dir
             );
-            println!("parquet manifest: {}", summary.parquet_manifest);
+            if !summary.parquet_manifest.is_empty() {
+                println!("parquet manifest: {}", summary.parquet_manifest);
+            }
+        }
+        Commands::HealthCheck { sources } => {
+            let source_ids = sources.iter().map(String::as_str).collect::<Vec<_>>();
+            let only = (!source_ids.is_empty()).then_some(source_ids.as_slice());
+            let results = rhof_sync::health_check_sources_from_env(only).await?;
+            for health in &results {
+                println!(
+                    "- {}: {:?} {}",
+                    health.source_id,
+                    health.status,
+                    health.message.as_deref().unwrap_or_default()
+                );
+            }
+            let broken = results.iter().filter(|health| health.is_broken()).count();
+            println!("health check complete: sources={} broken={broken}", results.len());
+            if broken > 0 {
+                anyhow::bail!("{broken} source(s) failed the health check");
+            }
         }
         Commands::Debug => {
             let info = rhof_sync::debug_summary_from_env()?;
             println!("{info}");
         }
+        Commands::ValidateSources => {
+            let issues = rhof_sync::validate_source_registry(std::path::Path::new("."))?;
+            if !issues.is_empty() {
+                for issue in &issues {
+                    println!("- {issue}");
+                }
+                anyhow::bail!("sources.yaml has {} problem(s)", issues.len());
+            }
+            println!("sources.yaml ok");
+        }
         Commands::Migrate => {
             rhof_sync::apply_migrations_from_env().await?;
             println!("migrations applied");
@@ -86,3 +349,68 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+fn print_sync_summary(summary: &rhof_sync::SyncRunSummary) {
+    println!(
+        "sync complete: run_id={} sources={} drafts={} reports={}",
+        summary.run_id, summary.enabled_sources, summary.parsed_drafts, summary.reports_dir
+    );
+    if !summary.parquet_manifest.is_empty() {
+        println!("parquet manifest: {}", summary.parquet_manifest);
+    }
+    println!("lifecycle: stale={} expired={}", summary.marked_stale, summary.marked_expired);
+    for failed in summary.failed_sources() {
+        println!(
+            "source failed: {} ({})",
+            failed.source_id,
+            failed.error.as_deref().unwrap_or("unknown error")
+        );
+    }
+}
+
+/// Run a sync while rendering its [`rhof_sync::SyncEvent`] stream as a progress bar on stderr.
+async fn sync_with_progress(sources: Vec<String>) -> Result<rhof_sync::SyncRunSummary> {
+    let config = rhof_sync::SyncConfig::load()?;
+    let (tx, mut rx) = tokio::sync::mpsc::channel(64);
+    let run = tokio::spawn(async move {
+        let source_ids = sources.iter().map(String::as_str).collect::<Vec<_>>();
+        if source_ids.is_empty() {
+            rhof_sync::run_sync_once_with_events(config, tx).await
+        } else {
+            rhof_sync::run_sync_for_sources_with_events(config, &source_ids, tx).await
+        }
+    });
+
+    let (mut total, mut done, mut drafts) = (0usize, 0usize, 0usize);
+    while let Some(event) = rx.recv().await {
+        let stage = match event {
+            rhof_sync::SyncEvent::RunStarted { run_id, sources } => {
+                total = sources;
+                format!("run {run_id}")
+            }
+            rhof_sync::SyncEvent::SourceStarted { source_id } => format!("fetching {source_id}"),
+            rhof_sync::SyncEvent::ArtifactStored { source_id, .. } => format!("parsing {source_id}"),
+            rhof_sync::SyncEvent::DraftsParsed { source_id, drafts: parsed } => {
+                done += 1;
+                drafts += parsed;
+                format!("{source_id}: {parsed} drafts")
+            }
+            rhof_sync::SyncEvent::SourceFailed { source_id, .. } => {
+                done += 1;
+                format!("{source_id}: failed")
+            }
+            rhof_sync::SyncEvent::Persisted { versions, .. } => format!("persisted {versions} new versions"),
+            rhof_sync::SyncEvent::Completed { .. } => "done".to_string(),
+            rhof_sync::SyncEvent::Failed { .. } => "failed".to_string(),
+        };
+        eprint!("\r\x1b[2K{} {done}/{total} sources, {drafts} drafts  {stage}", progress_bar(done, total));
+    }
+    eprintln!();
+    run.await?
+}
+
+fn progress_bar(done: usize, total: usize) -> String {
+    const WIDTH: usize = 24;
+    let filled = (done * WIDTH).checked_div(total).unwrap_or(0).min(WIDTH);
+    format!("[{}{}]", "#".repeat(filled), "-".repeat(WIDTH - filled))
+}
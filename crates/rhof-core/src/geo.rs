@@ -0,0 +1,213 @@
+//! Structured reading of free-text `geo_constraints` ("US & Canada only", "Worldwide (remote)").
+
+use serde::{Deserialize, Serialize};
+
+/// Where an opportunity can be worked from, parsed from its `geo_constraints` text.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GeoScope {
+    /// Open to (almost) every country, e.g. "Global (country-dependent tasks)".
+    pub global: bool,
+    /// The text says the work is remote / from home.
+    pub remote: bool,
+    /// ISO 3166-1 alpha-2 codes named in the text, sorted.
+    pub countries: Vec<String>,
+}
+
+impl GeoScope {
+    /// Whether someone in `country` (ISO 3166-1 alpha-2, any case) can take the opportunity.
+    pub fn allows(&self, country: &str) -> bool {
+        self.global || self.countries.iter().any(|code| code.eq_ignore_ascii_case(country))
+    }
+}
+
+const GLOBAL_PHRASES: &[&str] = &["global", "worldwide", "world-wide", "anywhere", "international", "all countries"];
+
+/// Bare "online" is left out: "online application" says nothing about where the work happens.
+const REMOTE_PHRASES: &[&str] = &["remote", "work from home", "wfh", "from home", "work online"];
+
+/// Lowercase names and aliases, matched as whole words.
+const COUNTRY_NAMES: &[(&str, &str)] = &[
+    ("united states of america", "US"),
+    ("united states", "US"),
+    ("usa", "US"),
+    ("north america", "US"),
+    ("north america", "CA"),
+    ("united kingdom", "GB"),
+    ("great britain", "GB"),
+    ("britain", "GB"),
+    ("england", "GB"),
+    ("scotland", "GB"),
+    ("canada", "CA"),
+    ("mexico", "MX"),
+    ("brazil", "BR"),
+    ("argentina", "AR"),
+    ("chile", "CL"),
+    ("colombia", "CO"),
+    ("peru", "PE"),
+    ("ireland", "IE"),
+    ("germany", "DE"),
+    ("france", "FR"),
+    ("spain", "ES"),
+    ("portugal", "PT"),
+    ("italy", "IT"),
+    ("netherlands", "NL"),
+    ("the netherlands", "NL"),
+    ("belgium", "BE"),
+    ("luxembourg", "LU"),
+    ("switzerland", "CH"),
+    ("austria", "AT"),
+    ("denmark", "DK"),
+    ("sweden", "SE"),
+    ("norway", "NO"),
+    ("finland", "FI"),
+    ("iceland", "IS"),
+    ("poland", "PL"),
+    ("czech republic", "CZ"),
+    ("czechia", "CZ"),
+    ("slovakia", "SK"),
+    ("hungary", "HU"),
+    ("romania", "RO"),
+    ("bulgaria", "BG"),
+    ("greece", "GR"),
+    ("croatia", "HR"),
+    ("slovenia", "SI"),
+    ("estonia", "EE"),
+    ("latvia", "LV"),
+    ("lithuania", "LT"),
+    ("cyprus", "CY"),
+    ("malta", "MT"),
+    ("ukraine", "UA"),
+    ("turkey", "TR"),
+    ("israel", "IL"),
+    ("egypt", "EG"),
+    ("nigeria", "NG"),
+    ("kenya", "KE"),
+    ("south africa", "ZA"),
+    ("india", "IN"),
+    ("pakistan", "PK"),
+    ("bangladesh", "BD"),
+    ("philippines", "PH"),
+    ("indonesia", "ID"),
+    ("malaysia", "MY"),
+    ("singapore", "SG"),
+    ("thailand", "TH"),
+    ("vietnam", "VN"),
+    ("japan", "JP"),
+    ("south korea", "KR"),
+    ("china", "CN"),
+    ("hong kong", "HK"),
+    ("taiwan", "TW"),
+    ("australia", "AU"),
+    ("new zealand", "NZ"),
+];
+
+/// Codes and abbreviations only trusted when written in capitals ("US only", not "join us").
+/// Codes that are also US state abbreviations (CA, DE, IN, ...) are left out, since
+/// "San Francisco, CA" names a state, not Canada.
+const COUNTRY_CODES: &[(&str, &str)] = &[
+    ("US", "US"),
+    ("USA", "US"),
+    ("UK", "GB"),
+    ("GB", "GB"),
+    ("AU", "AU"),
+    ("NZ", "NZ"),
+    ("IE", "IE"),
+    ("FR", "FR"),
+    ("ES", "ES"),
+    ("NL", "NL"),
+    ("PH", "PH"),
+    ("BR", "BR"),
+    ("MX", "MX"),
+    ("ZA", "ZA"),
+];
+
+/// European Union member states, for "EU only" / "European Union".
+const EU_MEMBERS: &[&str] = &[
+    "AT", "BE", "BG", "HR", "CY", "CZ", "DK", "EE", "FI", "FR", "DE", "GR", "HU", "IE", "IT", "LV", "LT", "LU",
+    "MT", "NL", "PL", "PT", "RO", "SK", "SI", "ES", "SE",
+];
+
+/// Parse a `geo_constraints` text. `None` when it names neither a scope nor a country.
+pub fn parse_geo_constraints(text: &str) -> Option<GeoScope> {
+    let lower = text.to_lowercase();
+    let mut scope = GeoScope {
+        global: GLOBAL_PHRASES.iter().any(|phrase| contains_words(&lower, phrase)),
+        remote: REMOTE_PHRASES.iter().any(|phrase| contains_words(&lower, phrase)),
+        countries: Vec::new(),
+    };
+    for (name, code) in COUNTRY_NAMES {
+        if contains_words(&lower, name) {
+            scope.countries.push(code.to_string());
+        }
+    }
+    for (token, code) in COUNTRY_CODES {
+        if contains_words(text, token) {
+            scope.countries.push(code.to_string());
+        }
+    }
+    if contains_words(text, "EU") || contains_words(&lower, "european union") {
+        scope.countries.extend(EU_MEMBERS.iter().map(|code| code.to_string()));
+    }
+    scope.countries.sort();
+    scope.countries.dedup();
+    (scope.global || scope.remote || !scope.countries.is_empty()).then_some(scope)
+}
+
+/// Whether `phrase` occurs in `text` as whole words: no letter or digit directly before or after it.
+fn contains_words(text: &str, phrase: &str) -> bool {
+    text.match_indices(phrase).any(|(at, _)| {
+        let before = text[..at].chars().next_back();
+        let after = text[at + phrase.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn countries(text: &str) -> Vec<String> {
+        parse_geo_constraints(text).map(|scope| scope.countries).unwrap_or_default()
+    }
+
+    #[test]
+    fn reads_scopes_and_country_names() {
+        let scope = parse_geo_constraints("US & Canada only").unwrap();
+        assert_eq!(scope.countries, vec!["CA", "US"]);
+        assert!(!scope.global && !scope.remote);
+        assert!(scope.allows("ca") && !scope.allows("GB"));
+
+        let scope = parse_geo_constraints("Global (country-dependent tasks)").unwrap();
+        assert!(scope.global && scope.allows("JP"));
+
+        let scope = parse_geo_constraints("Remote, EU residents").unwrap();
+        assert!(scope.remote);
+        assert!(scope.allows("DE") && !scope.allows("GB"));
+
+        assert_eq!(countries("UK or Ireland, work from home"), vec!["GB", "IE"]);
+        assert_eq!(parse_geo_constraints("See posting"), None);
+    }
+
+    #[test]
+    fn us_state_abbreviations_are_not_countries() {
+        assert_eq!(parse_geo_constraints("San Francisco, CA"), None);
+        assert_eq!(parse_geo_constraints("Indianapolis, IN"), None);
+        assert_eq!(parse_geo_constraints("Dover, DE"), None);
+        assert_eq!(countries("Austin, TX, US"), vec!["US"]);
+    }
+
+    #[test]
+    fn country_names_match_whole_words_only() {
+        assert_eq!(countries("South Korea"), vec!["KR"]);
+        assert_eq!(parse_geo_constraints("North Korea"), None);
+        assert_eq!(parse_geo_constraints("Indianapolis"), None);
+        assert_eq!(parse_geo_constraints("Chilean peso payouts"), None);
+        assert_eq!(countries("India-based contributors"), vec!["IN"]);
+    }
+
+    #[test]
+    fn online_applications_are_not_remote() {
+        assert_eq!(parse_geo_constraints("Online application required"), None);
+        assert!(parse_geo_constraints("Work online from anywhere").unwrap().remote);
+    }
+}
@@ -4,6 +4,10 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+pub mod geo;
+
+pub use geo::{parse_geo_constraints, GeoScope};
+
 pub const CRATE_NAME: &str = "rhof-core";
 
 /// Provenance pointer attached to canonical extracted values.
@@ -46,11 +50,21 @@ pub struct OpportunityDraft {
     pub source_id: String,
     pub listing_url: Option<String>,
     pub detail_url: Option<String>,
+    /// Stable listing id from the source (API id, query parameter), preferred for canonical keys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// ISO 639-3 code of the listing's language (e.g. `eng`, `deu`); the sync pipeline detects it
+    /// from the title and description when the adapter leaves it unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
     pub fetched_at: DateTime<Utc>,
     pub extractor_version: String,
     pub title: Field<String>,
     pub description: Field<String>,
     pub pay_model: Field<String>,
+    /// What one pay rate pays for: `hour`, `minute`, `word`, `task`, `study`, `week` or `month`.
+    #[serde(default)]
+    pub pay_unit: Field<String>,
     pub pay_rate_min: Field<f64>,
     pub pay_rate_max: Field<f64>,
     pub currency: Field<String>,
@@ -63,6 +77,19 @@ pub struct OpportunityDraft {
     pub requirements: Field<Vec<String>>,
 }
 
+impl OpportunityDraft {
+    /// The language of the title and description, when whatlang is confident about it.
+    pub fn detect_language(&self) -> Option<String> {
+        let text = [self.title.value.as_deref(), self.description.value.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join("\n");
+        let info = whatlang::detect(&text)?;
+        info.is_reliable().then(|| info.lang().code().to_string())
+    }
+}
+
 /// Canonical persisted opportunity representation with provenance-bearing fields.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Opportunity {
@@ -0,0 +1,150 @@
+//! robots.txt parsing and matching (RFC 9309 subset: groups, `Allow`/`Disallow`, `*` and `$`).
+
+/// Whether a live fetch consults the host's robots.txt first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RobotsPolicy {
+    #[default]
+    Respect,
+    /// Per-source override for hosts that allow the crawl out of band.
+    Ignore,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RobotsRules {
+    /// `(allow, path pattern)` of the group that applies to our user agent.
+    rules: Vec<(bool, String)>,
+}
+
+impl RobotsRules {
+    pub fn allow_all() -> Self {
+        Self::default()
+    }
+
+    pub fn disallow_all() -> Self {
+        Self {
+            rules: vec![(false, "/".to_string())],
+        }
+    }
+
+    /// Rules of the group naming `user_agent`'s product token (e.g. `rhof-bot` for
+    /// `rhof-bot/0.1`), falling back to the `*` group. Groups naming the same agent are merged.
+    pub fn parse(text: &str, user_agent: &str) -> Self {
+        let token = user_agent
+            .split('/')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        let mut specific = Vec::new();
+        let mut wildcard = Vec::new();
+        let mut found_specific = false;
+        let mut group_agents: Vec<String> = Vec::new();
+        let mut in_rules = false;
+
+        for line in text.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let key = key.trim().to_ascii_lowercase();
+            let value = value.trim();
+            match key.as_str() {
+                "user-agent" => {
+                    if in_rules {
+                        group_agents.clear();
+                        in_rules = false;
+                    }
+                    group_agents.push(value.to_ascii_lowercase());
+                }
+                "allow" | "disallow" => {
+                    in_rules = true;
+                    // An empty `Disallow:` allows everything and adds no rule.
+                    if value.is_empty() {
+                        continue;
+                    }
+                    let rule = (key == "allow", value.to_string());
+                    if !token.is_empty() && group_agents.contains(&token) {
+                        found_specific = true;
+                        specific.push(rule);
+                    } else if group_agents.iter().any(|agent| agent == "*") {
+                        wildcard.push(rule);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self {
+            rules: if found_specific { specific } else { wildcard },
+        }
+    }
+
+    /// The longest matching rule wins; on a tie `Allow` wins; no match allows.
+    pub fn is_allowed(&self, path: &str) -> bool {
+        self.rules
+            .iter()
+            .filter(|(_, pattern)| pattern_matches(pattern, path))
+            .max_by_key(|(allow, pattern)| (pattern.len(), *allow))
+            .is_none_or(|(allow, _)| *allow)
+    }
+}
+
+/// Match a robots.txt path pattern: `*` matches any run of characters and a trailing `$`
+/// anchors the end; otherwise the pattern is a prefix.
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(pattern) => (pattern, true),
+        None => (pattern, false),
+    };
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = path.strip_prefix(first) else {
+        return false;
+    };
+    let parts = parts.collect::<Vec<_>>();
+    for (index, part) in parts.iter().enumerate() {
+        let is_last = index + 1 == parts.len();
+        if is_last && anchored {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+    !anchored || rest.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_the_matching_group_and_longest_rule() {
+        let text = "\
+User-agent: *
+Disallow: /private
+Allow: /private/jobs
+
+User-agent: other-bot
+Disallow: /
+
+User-agent: RHOF-Bot
+Disallow: /admin # ours only
+Disallow: /*.pdf$
+";
+        let ours = RobotsRules::parse(text, "rhof-bot/0.1");
+        assert!(ours.is_allowed("/private"));
+        assert!(!ours.is_allowed("/admin/users"));
+        assert!(!ours.is_allowed("/files/guide.pdf"));
+        assert!(ours.is_allowed("/files/guide.pdf?download=1"));
+
+        let anyone = RobotsRules::parse(text, "curl/8.0");
+        assert!(!anyone.is_allowed("/private/settings"));
+        assert!(anyone.is_allowed("/private/jobs/42"));
+        assert!(anyone.is_allowed("/jobs"));
+
+        assert!(RobotsRules::parse("User-agent: *\nDisallow:\n", "rhof-bot").is_allowed("/"));
+        assert!(!RobotsRules::disallow_all().is_allowed("/jobs"));
+    }
+}
@@ -0,0 +1,319 @@
+//! WARC/1.1 output of live fetches: a `request` and a `response` record per fetch, so captures
+//! open in standard web-archiving tools (pywb, warcio, ...).
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use chrono::{DateTime, SecondsFormat, Utc};
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::{ArtifactStore, FetchedResponse};
+
+/// Which WARC file a fetch is appended to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarcSplit {
+    /// `<dir>/<run_id>.warc`
+    #[default]
+    Run,
+    /// `<dir>/<run_id>/<source_id>.warc`
+    Source,
+}
+
+impl std::str::FromStr for WarcSplit {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "run" => Ok(Self::Run),
+            "source" => Ok(Self::Source),
+            other => anyhow::bail!("unknown WARC split `{other}`; expected `run` or `source`"),
+        }
+    }
+}
+
+/// Response headers that describe the wire encoding rather than the (decoded) body we record;
+/// kept under an `X-Archive-Orig-` prefix, as pywb does.
+const WIRE_HEADERS: [&str; 3] = ["content-encoding", "content-length", "transfer-encoding"];
+
+/// Headers whose values are credentials. They are written as `[redacted]`, as is any header
+/// marked sensitive (e.g. an API's configured auth header), so secrets never land on disk.
+const SECRET_HEADERS: [&str; 3] = ["authorization", "proxy-authorization", "cookie"];
+
+/// Appends fetches to WARC files under a directory. Each file starts with a `warcinfo` record.
+#[derive(Debug)]
+pub struct WarcArchive {
+    dir: PathBuf,
+    split: WarcSplit,
+    /// Serializes appends so records from concurrent fetches never interleave.
+    write: Mutex<()>,
+}
+
+impl WarcArchive {
+    pub fn new(dir: impl Into<PathBuf>, split: WarcSplit) -> Self {
+        Self { dir: dir.into(), split, write: Mutex::new(()) }
+    }
+
+    pub fn path_for(&self, run_id: Uuid, source_id: &str) -> PathBuf {
+        match self.split {
+            WarcSplit::Run => self.dir.join(format!("{run_id}.warc")),
+            WarcSplit::Source => self.dir.join(run_id.to_string()).join(format!("{source_id}.warc")),
+        }
+    }
+
+    /// Append the request and response records of one fetch of `url`.
+    pub async fn append(
+        &self,
+        run_id: Uuid,
+        source_id: &str,
+        url: &str,
+        response: &FetchedResponse,
+        fetched_at: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        let path = self.path_for(run_id, source_id);
+        let response_id = record_id();
+        let mut out = Vec::new();
+        out.extend(warc_record(
+            "request",
+            &[
+                ("WARC-Target-URI", url.to_string()),
+                ("WARC-Concurrent-To", response_id.clone()),
+            ],
+            fetched_at,
+            "application/http;msgtype=request",
+            &http_request_block(url, &response.request_headers),
+        ));
+        let mut block = http_response_head(response);
+        block.extend_from_slice(&response.body);
+        out.extend(warc_record_with_id(
+            response_id,
+            "response",
+            &[
+                ("WARC-Target-URI", url.to_string()),
+                ("WARC-Payload-Digest", format!("sha256:{}", ArtifactStore::sha256_hex(&response.body))),
+            ],
+            fetched_at,
+            "application/http;msgtype=response",
+            &block,
+        ));
+
+        let _write = self.write.lock().await;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("creating WARC directory {}", parent.display()))?;
+        }
+        let is_new = !fs::try_exists(&path).await.unwrap_or(false);
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await
+            .with_context(|| format!("opening WARC file {}", path.display()))?;
+        if is_new {
+            file.write_all(&warcinfo_record(&path, fetched_at)).await?;
+        }
+        file.write_all(&out)
+            .await
+            .with_context(|| format!("appending to WARC file {}", path.display()))?;
+        file.flush().await?;
+        Ok(())
+    }
+}
+
+fn record_id() -> String {
+    format!("<urn:uuid:{}>", Uuid::new_v4())
+}
+
+fn warc_record(
+    warc_type: &str,
+    fields: &[(&str, String)],
+    date: DateTime<Utc>,
+    content_type: &str,
+    block: &[u8],
+) -> Vec<u8> {
+    warc_record_with_id(record_id(), warc_type, fields, date, content_type, block)
+}
+
+fn warc_record_with_id(
+    id: String,
+    warc_type: &str,
+    fields: &[(&str, String)],
+    date: DateTime<Utc>,
+    content_type: &str,
+    block: &[u8],
+) -> Vec<u8> {
+    let mut head = format!(
+        "WARC/1.1\r\nWARC-Type: {warc_type}\r\nWARC-Record-ID: {id}\r\nWARC-Date: {}\r\n",
+        date.to_rfc3339_opts(SecondsFormat::Secs, true)
+    );
+    for (name, value) in fields {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str(&format!("Content-Type: {content_type}\r\nContent-Length: {}\r\n\r\n", block.len()));
+    let mut record = head.into_bytes();
+    record.extend_from_slice(block);
+    record.extend_from_slice(b"\r\n\r\n");
+    record
+}
+
+fn warcinfo_record(path: &Path, date: DateTime<Utc>) -> Vec<u8> {
+    let filename = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+    let block = format!(
+        "software: {}/{}\r\nformat: WARC File Format 1.1\r\n",
+        crate::CRATE_NAME,
+        env!("CARGO_PKG_VERSION")
+    );
+    warc_record("warcinfo", &[("WARC-Filename", filename)], date, "application/warc-fields", block.as_bytes())
+}
+
+fn push_headers(out: &mut String, headers: &HeaderMap, rename_wire: bool) {
+    for (name, value) in headers {
+        let value = if value.is_sensitive() || SECRET_HEADERS.contains(&name.as_str()) {
+            "[redacted]".into()
+        } else {
+            String::from_utf8_lossy(value.as_bytes())
+        };
+        if rename_wire && WIRE_HEADERS.contains(&name.as_str()) {
+            out.push_str(&format!("X-Archive-Orig-{name}: {value}\r\n"));
+        } else {
+            out.push_str(&format!("{name}: {value}\r\n"));
+        }
+    }
+}
+
+fn http_request_block(url: &str, headers: &HeaderMap) -> Vec<u8> {
+    let (target, host) = match reqwest::Url::parse(url) {
+        Ok(parsed) => {
+            let target = match parsed.query() {
+                Some(query) => format!("{}?{query}", parsed.path()),
+                None => parsed.path().to_string(),
+            };
+            let host = match (parsed.host_str(), parsed.port()) {
+                (Some(host), Some(port)) => format!("{host}:{port}"),
+                (Some(host), None) => host.to_string(),
+                _ => String::new(),
+            };
+            (target, host)
+        }
+        Err(_) => (url.to_string(), String::new()),
+    };
+    let mut out = format!("GET {target} HTTP/1.1\r\n");
+    if !headers.contains_key(reqwest::header::HOST) && !host.is_empty() {
+        out.push_str(&format!("host: {host}\r\n"));
+    }
+    push_headers(&mut out, headers, false);
+    out.push_str("\r\n");
+    out.into_bytes()
+}
+
+/// Status line and headers of the decoded response; `Content-Length` is the recorded body's.
+fn http_response_head(response: &FetchedResponse) -> Vec<u8> {
+    let mut out = format!(
+        "{:?} {} {}\r\n",
+        response.version,
+        response.status.as_u16(),
+        response.status.canonical_reason().unwrap_or_default()
+    );
+    push_headers(&mut out, &response.headers, true);
+    out.push_str(&format!("content-length: {}\r\n\r\n", response.body.len()));
+    out.into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderValue, CONTENT_ENCODING, CONTENT_TYPE, USER_AGENT};
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn appends_warcinfo_once_then_request_and_response_records() {
+        let dir = tempfile::tempdir().unwrap();
+        let archive = WarcArchive::new(dir.path(), WarcSplit::Source);
+        let mut request_headers = HeaderMap::new();
+        request_headers.insert(USER_AGENT, HeaderValue::from_static("rhof-bot/0.1"));
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/html"));
+        headers.insert(CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+        let response = FetchedResponse {
+            status: StatusCode::OK,
+            final_url: "https://example.com/jobs?page=2".to_string(),
+            body: b"<html>jobs</html>".to_vec(),
+            validators: Default::default(),
+            version: reqwest::Version::HTTP_11,
+            headers,
+            request_headers,
+        };
+        let run_id = Uuid::new_v4();
+        let at = DateTime::parse_from_rfc3339("2026-02-24T12:00:00Z").unwrap().with_timezone(&Utc);
+        for _ in 0..2 {
+            archive.append(run_id, "example", "https://example.com/jobs?page=2", &response, at).await.unwrap();
+        }
+
+        let path = dir.path().join(run_id.to_string()).join("example.warc");
+        assert_eq!(archive.path_for(run_id, "example"), path);
+        let warc = std::fs::read_to_string(path).unwrap();
+        assert!(warc.starts_with("WARC/1.1\r\nWARC-Type: warcinfo\r\n"), "{warc}");
+        assert_eq!(warc.matches("WARC-Type: warcinfo").count(), 1);
+        assert_eq!(warc.matches("WARC-Type: request").count(), 2);
+        assert_eq!(warc.matches("WARC-Type: response").count(), 2);
+        assert!(warc.contains("WARC-Date: 2026-02-24T12:00:00Z\r\n"));
+        assert!(warc.contains("GET /jobs?page=2 HTTP/1.1\r\nhost: example.com\r\nuser-agent: rhof-bot/0.1\r\n\r\n"));
+        assert!(warc.contains(
+            "HTTP/1.1 200 OK\r\ncontent-type: text/html\r\nX-Archive-Orig-content-encoding: gzip\r\ncontent-length: 17\r\n\r\n<html>jobs</html>\r\n\r\n"
+        ));
+        let response_record = warc.split("WARC-Type: response").nth(1).unwrap();
+        let block_length = "HTTP/1.1 200 OK\r\ncontent-type: text/html\r\nX-Archive-Orig-content-encoding: gzip\r\ncontent-length: 17\r\n\r\n<html>jobs</html>".len();
+        assert!(response_record.contains(&format!("Content-Length: {block_length}\r\n")), "{response_record}");
+    }
+
+    #[tokio::test]
+    async fn authenticated_fetches_are_archived_without_their_credentials() {
+        use crate::{HttpClientConfig, HttpFetcher, RobotsPolicy, SourceHeaders};
+        use tokio::io::AsyncReadExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/v1/jobs", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let body = r#"{"jobs":[]}"#;
+                let response = format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}", body.len());
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+
+        let dir = tempfile::tempdir().unwrap();
+        let http = HttpFetcher::new(HttpClientConfig::default())
+            .unwrap()
+            .with_warc(WarcArchive::new(dir.path(), WarcSplit::Run));
+        let source_headers = SourceHeaders {
+            headers: vec![("Accept-Language".to_string(), "en".to_string())],
+            cookies: vec![("session".to_string(), "cookie-secret".to_string())],
+        };
+        http.set_source_headers("api", &source_headers).await;
+        let run_id = Uuid::new_v4();
+        for auth in [("Authorization", "Bearer bearer-secret"), ("X-Api-Key", "key-secret")] {
+            let headers = [(auth.0.to_string(), auth.1.to_string())];
+            let response = http.fetch_bytes_with_headers(run_id, "api", &url, RobotsPolicy::Ignore, &headers).await.unwrap();
+            assert!(response.status.is_success());
+        }
+
+        let warc = std::fs::read_to_string(dir.path().join(format!("{run_id}.warc"))).unwrap();
+        assert_eq!(warc.matches("WARC-Type: request").count(), 2);
+        for secret in ["bearer-secret", "key-secret", "cookie-secret"] {
+            assert!(!warc.contains(secret), "{secret} leaked into {warc}");
+        }
+        assert!(warc.contains("authorization: [redacted]\r\n"), "{warc}");
+        assert!(warc.contains("x-api-key: [redacted]\r\n"), "{warc}");
+        assert_eq!(warc.matches("cookie: [redacted]\r\n").count(), 2);
+        assert!(warc.contains("accept-language: en\r\n"), "{warc}");
+    }
+}
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_opportunities_source_status;
+
+ALTER TABLE opportunities
+    DROP COLUMN IF EXISTS last_seen_run_id,
+    DROP COLUMN IF EXISTS missed_runs;
@@ -0,0 +1,5 @@
+ALTER TABLE opportunities
+    ADD COLUMN IF NOT EXISTS missed_runs INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS last_seen_run_id UUID REFERENCES fetch_runs(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_opportunities_source_status ON opportunities (source_id, status);
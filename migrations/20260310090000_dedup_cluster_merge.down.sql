@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_opportunities_merged_into;
+
+ALTER TABLE opportunities
+    DROP COLUMN IF EXISTS merged_into_id;
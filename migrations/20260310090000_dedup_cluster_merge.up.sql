@@ -0,0 +1,4 @@
+ALTER TABLE opportunities
+    ADD COLUMN IF NOT EXISTS merged_into_id UUID REFERENCES opportunities(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_opportunities_merged_into ON opportunities (merged_into_id);
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS quarantined_drafts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    fetch_run_id UUID REFERENCES fetch_runs(id) ON DELETE CASCADE,
+    source_id UUID REFERENCES sources(id) ON DELETE SET NULL,
+    canonical_key TEXT NOT NULL,
+    reasons_json JSONB NOT NULL DEFAULT '[]'::jsonb,
+    draft_json JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_quarantined_drafts_fetch_run ON quarantined_drafts (fetch_run_id);
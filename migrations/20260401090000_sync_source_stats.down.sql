@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_sync_source_stats_source;
+DROP TABLE IF EXISTS sync_source_stats;
@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS sync_source_stats (
+    fetch_run_id UUID NOT NULL REFERENCES fetch_runs(id) ON DELETE CASCADE,
+    source_id UUID NOT NULL REFERENCES sources(id) ON DELETE CASCADE,
+    fetched INTEGER NOT NULL DEFAULT 0,
+    parsed INTEGER NOT NULL DEFAULT 0,
+    persisted INTEGER NOT NULL DEFAULT 0,
+    review_required INTEGER NOT NULL DEFAULT 0,
+    quarantined INTEGER NOT NULL DEFAULT 0,
+    errors INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (fetch_run_id, source_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_sync_source_stats_source ON sync_source_stats (source_id, created_at);
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS fetch_validators (
+    source_id UUID NOT NULL REFERENCES sources(id) ON DELETE CASCADE,
+    url TEXT NOT NULL,
+    etag TEXT,
+    last_modified TEXT,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (source_id, url)
+);
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS source_layouts (
+    source_id UUID PRIMARY KEY REFERENCES sources(id) ON DELETE CASCADE,
+    fingerprint TEXT NOT NULL,
+    paths_json JSONB NOT NULL,
+    fetch_run_id UUID NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_fetch_attempts_source;
+DROP INDEX IF EXISTS idx_fetch_attempts_run;
+DROP TABLE IF EXISTS fetch_attempts;
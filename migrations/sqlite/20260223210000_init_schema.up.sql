@@ -0,0 +1,125 @@
+-- SQLite mirror of ../20260223210000_init_schema.up.sql.
+-- UUIDs are stored as hyphenated TEXT, JSON as TEXT and timestamps as RFC 3339 TEXT.
+
+CREATE TABLE IF NOT EXISTS sources (
+    id TEXT PRIMARY KEY NOT NULL,
+    source_id TEXT NOT NULL UNIQUE,
+    display_name TEXT NOT NULL,
+    crawlability TEXT NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    config_json TEXT NOT NULL DEFAULT '{}',
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+CREATE TABLE IF NOT EXISTS fetch_runs (
+    id TEXT PRIMARY KEY NOT NULL,
+    started_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    finished_at TEXT,
+    status TEXT NOT NULL DEFAULT 'started',
+    summary_json TEXT NOT NULL DEFAULT '{}',
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+CREATE TABLE IF NOT EXISTS raw_artifacts (
+    id TEXT PRIMARY KEY NOT NULL,
+    fetch_run_id TEXT REFERENCES fetch_runs(id) ON DELETE SET NULL,
+    source_id TEXT REFERENCES sources(id) ON DELETE SET NULL,
+    source_url TEXT NOT NULL,
+    storage_path TEXT NOT NULL,
+    content_type TEXT,
+    content_hash TEXT NOT NULL,
+    http_status INTEGER,
+    byte_size INTEGER,
+    fetched_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    metadata_json TEXT NOT NULL DEFAULT '{}',
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+CREATE TABLE IF NOT EXISTS opportunities (
+    id TEXT PRIMARY KEY NOT NULL,
+    source_id TEXT REFERENCES sources(id) ON DELETE SET NULL,
+    canonical_key TEXT NOT NULL,
+    apply_url TEXT,
+    status TEXT NOT NULL DEFAULT 'active',
+    first_seen_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    last_seen_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    current_version_id TEXT REFERENCES opportunity_versions(id) ON DELETE SET NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+CREATE TABLE IF NOT EXISTS opportunity_versions (
+    id TEXT PRIMARY KEY NOT NULL,
+    opportunity_id TEXT NOT NULL REFERENCES opportunities(id) ON DELETE CASCADE,
+    raw_artifact_id TEXT REFERENCES raw_artifacts(id) ON DELETE SET NULL,
+    version_no INTEGER NOT NULL,
+    data_json TEXT NOT NULL,
+    diff_json TEXT NOT NULL DEFAULT '{}',
+    evidence_json TEXT NOT NULL DEFAULT '{}',
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    UNIQUE (opportunity_id, version_no)
+);
+
+CREATE TABLE IF NOT EXISTS tags (
+    id TEXT PRIMARY KEY NOT NULL,
+    key TEXT NOT NULL UNIQUE,
+    label TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+CREATE TABLE IF NOT EXISTS opportunity_tags (
+    opportunity_id TEXT NOT NULL REFERENCES opportunities(id) ON DELETE CASCADE,
+    tag_id TEXT NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    PRIMARY KEY (opportunity_id, tag_id)
+);
+
+CREATE TABLE IF NOT EXISTS risk_flags (
+    id TEXT PRIMARY KEY NOT NULL,
+    key TEXT NOT NULL UNIQUE,
+    label TEXT NOT NULL,
+    severity TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+CREATE TABLE IF NOT EXISTS opportunity_risk_flags (
+    opportunity_id TEXT NOT NULL REFERENCES opportunities(id) ON DELETE CASCADE,
+    risk_flag_id TEXT NOT NULL REFERENCES risk_flags(id) ON DELETE CASCADE,
+    reason TEXT,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    PRIMARY KEY (opportunity_id, risk_flag_id)
+);
+
+CREATE TABLE IF NOT EXISTS dedup_clusters (
+    id TEXT PRIMARY KEY NOT NULL,
+    confidence_score REAL NOT NULL DEFAULT 0.0,
+    status TEXT NOT NULL DEFAULT 'proposed',
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+CREATE TABLE IF NOT EXISTS dedup_cluster_members (
+    dedup_cluster_id TEXT NOT NULL REFERENCES dedup_clusters(id) ON DELETE CASCADE,
+    opportunity_id TEXT NOT NULL REFERENCES opportunities(id) ON DELETE CASCADE,
+    member_score REAL,
+    is_primary BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    PRIMARY KEY (dedup_cluster_id, opportunity_id)
+);
+
+CREATE TABLE IF NOT EXISTS review_items (
+    id TEXT PRIMARY KEY NOT NULL,
+    item_type TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'open',
+    dedup_cluster_id TEXT REFERENCES dedup_clusters(id) ON DELETE SET NULL,
+    opportunity_id TEXT REFERENCES opportunities(id) ON DELETE SET NULL,
+    payload_json TEXT NOT NULL DEFAULT '{}',
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+    resolved_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_opportunities_canonical_key ON opportunities (canonical_key);
+CREATE INDEX IF NOT EXISTS idx_opportunities_apply_url ON opportunities (apply_url);
+CREATE INDEX IF NOT EXISTS idx_raw_artifacts_content_hash ON raw_artifacts (content_hash);
+CREATE INDEX IF NOT EXISTS idx_opportunity_versions_created_at ON opportunity_versions (created_at);
@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_opportunities_source_status;
+
+ALTER TABLE opportunities DROP COLUMN last_seen_run_id;
+ALTER TABLE opportunities DROP COLUMN missed_runs;
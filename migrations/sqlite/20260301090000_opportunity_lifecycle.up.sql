@@ -0,0 +1,4 @@
+ALTER TABLE opportunities ADD COLUMN missed_runs INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE opportunities ADD COLUMN last_seen_run_id TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_opportunities_source_status ON opportunities (source_id, status);
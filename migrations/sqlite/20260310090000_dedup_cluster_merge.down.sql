@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_opportunities_merged_into;
+
+ALTER TABLE opportunities DROP COLUMN merged_into_id;
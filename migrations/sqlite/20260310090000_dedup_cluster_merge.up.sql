@@ -0,0 +1,3 @@
+ALTER TABLE opportunities ADD COLUMN merged_into_id TEXT REFERENCES opportunities(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_opportunities_merged_into ON opportunities (merged_into_id);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_quarantined_drafts_fetch_run;
+DROP TABLE IF EXISTS quarantined_drafts;
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS quarantined_drafts (
+    id TEXT PRIMARY KEY NOT NULL,
+    fetch_run_id TEXT REFERENCES fetch_runs(id) ON DELETE CASCADE,
+    source_id TEXT REFERENCES sources(id) ON DELETE SET NULL,
+    canonical_key TEXT NOT NULL,
+    reasons_json TEXT NOT NULL DEFAULT '[]',
+    draft_json TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_quarantined_drafts_fetch_run ON quarantined_drafts (fetch_run_id);
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS source_layouts (
+    source_id TEXT PRIMARY KEY REFERENCES sources(id) ON DELETE CASCADE,
+    fingerprint TEXT NOT NULL,
+    paths_json TEXT NOT NULL,
+    fetch_run_id TEXT NOT NULL,
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS fetch_attempts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    fetch_run_id TEXT NOT NULL REFERENCES fetch_runs(id) ON DELETE CASCADE,
+    source_id TEXT NOT NULL REFERENCES sources(id) ON DELETE CASCADE,
+    url TEXT NOT NULL,
+    attempt INTEGER NOT NULL,
+    status INTEGER,
+    latency_ms INTEGER NOT NULL,
+    outcome TEXT NOT NULL,
+    error TEXT,
+    attempted_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_fetch_attempts_run ON fetch_attempts (fetch_run_id);
+CREATE INDEX IF NOT EXISTS idx_fetch_attempts_source ON fetch_attempts (source_id, attempted_at);